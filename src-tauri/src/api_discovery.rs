@@ -0,0 +1,173 @@
+use futures::future::join_all;
+use kube::{
+    api::ApiResource,
+    core::GroupVersion,
+    discovery::{self, Scope},
+    Client,
+};
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::k8s_api::{K8sClient, K8sClientPool, K8sError, Result};
+
+/// An API resource served by the cluster, as reported by API discovery.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiResourceInfo {
+    pub group: String,
+    pub version: String,
+    pub kind: String,
+    pub plural: String,
+    /// "Namespaced" or "Cluster", matching `CrdResourceInfo::scope`
+    pub scope: String,
+    pub verbs: Vec<String>,
+}
+
+impl ApiResourceInfo {
+    pub fn api_version(&self) -> String {
+        if self.group.is_empty() {
+            self.version.clone()
+        } else {
+            format!("{}/{}", self.group, self.version)
+        }
+    }
+
+    pub fn api_resource(&self) -> ApiResource {
+        ApiResource {
+            group: self.group.clone(),
+            version: self.version.clone(),
+            api_version: self.api_version(),
+            kind: self.kind.clone(),
+            plural: self.plural.clone(),
+        }
+    }
+
+    pub fn is_namespaced(&self) -> bool {
+        self.scope == "Namespaced"
+    }
+
+    pub fn supports(&self, verb: &str) -> bool {
+        self.verbs.iter().any(|v| v == verb)
+    }
+}
+
+/// Builds an `ApiResource` for dynamic access. The core group has no group
+/// prefix in its apiVersion.
+pub(crate) fn dynamic_api_resource(group: &str, version: &str, plural: &str) -> ApiResource {
+    ApiResource {
+        group: group.to_string(),
+        version: version.to_string(),
+        api_version: if group.is_empty() {
+            version.to_string()
+        } else {
+            format!("{}/{}", group, version)
+        },
+        kind: String::new(),
+        plural: plural.to_string(),
+    }
+}
+
+/// Queries the preferred version of every API group the server advertises,
+/// including aggregated APIs such as metrics.k8s.io. Groups that fail to
+/// answer (commonly an unavailable aggregated API) are skipped rather than
+/// failing the whole discovery.
+pub(crate) async fn discover_api_resources(client: &Client) -> Result<Vec<ApiResourceInfo>> {
+    let mut group_versions = vec![GroupVersion::gv("", "v1")];
+    for group in client.list_api_groups().await?.groups {
+        let version = group
+            .preferred_version
+            .map(|v| v.version)
+            .or_else(|| group.versions.first().map(|v| v.version.clone()));
+        if let Some(version) = version {
+            group_versions.push(GroupVersion::gv(&group.name, &version));
+        }
+    }
+
+    let groups = join_all(
+        group_versions
+            .iter()
+            .map(|gv| discovery::pinned_group(client, gv)),
+    )
+    .await;
+
+    let mut resources = Vec::new();
+    for (gv, group) in group_versions.iter().zip(groups) {
+        let group = match group {
+            Ok(group) => group,
+            Err(e) => {
+                log::warn!("Skipping API group {}/{}: {}", gv.group, gv.version, e);
+                continue;
+            }
+        };
+        for (ar, caps) in group.recommended_resources() {
+            resources.push(ApiResourceInfo {
+                group: ar.group,
+                version: ar.version,
+                kind: ar.kind,
+                plural: ar.plural,
+                scope: match caps.scope {
+                    Scope::Namespaced => "Namespaced".to_string(),
+                    Scope::Cluster => "Cluster".to_string(),
+                },
+                verbs: caps.operations,
+            });
+        }
+    }
+    Ok(resources)
+}
+
+/// Built-in groups are preferred when a kind or plural is served by several
+/// groups, e.g. `pods` from the core group over `pods.metrics.k8s.io`.
+fn group_rank(group: &str) -> u8 {
+    if group.is_empty() {
+        0
+    } else if !group.contains('.') || group.ends_with(".k8s.io") {
+        1
+    } else {
+        2
+    }
+}
+
+/// Finds the resource a kind string refers to. Accepts the kind
+/// ("PodDisruptionBudget"), its plural in any case ("PodDisruptionBudgets",
+/// "poddisruptionbudgets") or a group-qualified plural such as
+/// "pods.metrics.k8s.io".
+pub(crate) fn find_api_resource<'a>(
+    resources: &'a [ApiResourceInfo],
+    kind: &str,
+) -> Option<&'a ApiResourceInfo> {
+    resources
+        .iter()
+        .filter(|r| {
+            r.kind.eq_ignore_ascii_case(kind)
+                || r.plural.eq_ignore_ascii_case(kind)
+                || (!r.group.is_empty()
+                    && format!("{}.{}", r.plural, r.group).eq_ignore_ascii_case(kind))
+        })
+        .min_by_key(|r| group_rank(&r.group))
+}
+
+/// Resolves a kind that has no typed handler through API discovery.
+pub(crate) async fn resolve_kind(
+    client: &dyn K8sClient,
+    kind: &str,
+) -> Result<Option<ApiResourceInfo>> {
+    let resources = client.api_resources().await?;
+    Ok(find_api_resource(&resources, kind).cloned())
+}
+
+#[tauri::command]
+pub async fn list_api_resources(
+    client_pool: tauri::State<'_, K8sClientPool>,
+    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+) -> Result<Vec<ApiResourceInfo>> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = crate::k8s_api::get_or_create_client(&client_pool, context, kc_path).await?;
+    let resources: Arc<Vec<ApiResourceInfo>> = client.api_resources().await?;
+    let mut result = resources.as_ref().clone();
+    result.sort_by(|a, b| a.group.cmp(&b.group).then_with(|| a.kind.cmp(&b.kind)));
+    Ok(result)
+}
@@ -0,0 +1,381 @@
+use kube::{
+    api::{Api, DynamicObject, Patch, PatchParams},
+    core::GroupVersionKind,
+    discovery::{self, Scope},
+    Client,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::k8s_api::{
+    bad_request, fetch_resource_detail, get_or_create_client, get_or_create_raw_client, K8sClient,
+    K8sClientPool, K8sError, Result,
+};
+
+const FIELD_MANAGER: &str = "swimmer";
+
+#[derive(Debug, Serialize)]
+pub struct ApplyResult {
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    /// "applied", "conflict" or "error"
+    pub status: String,
+    pub message: Option<String>,
+    pub resource: Option<Value>,
+}
+
+/// A manifest document together with the API it resolves to.
+pub(crate) struct ResolvedDocument {
+    pub api: Api<DynamicObject>,
+    pub api_version: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    /// `cr:group/version/plural/scope` kind for reading the live object
+    pub detail_kind: String,
+    pub object: Value,
+}
+
+/// Splits a (possibly multi-document) YAML string into JSON values, skipping empty documents.
+pub(crate) fn parse_yaml_documents(yaml: &str) -> Result<Vec<Value>> {
+    use serde::Deserialize;
+
+    let mut documents = Vec::new();
+    for document in serde_yml::Deserializer::from_str(yaml) {
+        let value = Value::deserialize(document)?;
+        if !value.is_null() {
+            documents.push(value);
+        }
+    }
+    Ok(documents)
+}
+
+/// Resolves a document's apiVersion/kind through API discovery and builds an API
+/// handle for it. Namespaced objects without a namespace fall back to
+/// `default_namespace`, then to "default".
+pub(crate) async fn resolve_document(
+    client: &Client,
+    mut object: Value,
+    default_namespace: Option<&str>,
+) -> Result<ResolvedDocument> {
+    let api_version = object
+        .get("apiVersion")
+        .and_then(Value::as_str)
+        .ok_or_else(|| bad_request("Document is missing apiVersion".to_string()))?
+        .to_string();
+    let kind = object
+        .get("kind")
+        .and_then(Value::as_str)
+        .ok_or_else(|| bad_request("Document is missing kind".to_string()))?
+        .to_string();
+    let name = object
+        .pointer("/metadata/name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| bad_request(format!("{} is missing metadata.name", kind)))?
+        .to_string();
+
+    let (group, version) = match api_version.split_once('/') {
+        Some((group, version)) => (group, version),
+        None => ("", api_version.as_str()),
+    };
+    let gvk = GroupVersionKind::gvk(group, version, &kind);
+    let (ar, caps) = discovery::pinned_kind(client, &gvk).await?;
+    let detail_kind = format!(
+        "cr:{}/{}/{}/{}",
+        ar.group,
+        ar.version,
+        ar.plural,
+        if caps.scope == Scope::Namespaced {
+            "Namespaced"
+        } else {
+            "Cluster"
+        }
+    );
+
+    let (api, namespace) = if caps.scope == Scope::Namespaced {
+        let namespace = object
+            .pointer("/metadata/namespace")
+            .and_then(Value::as_str)
+            .or(default_namespace)
+            .unwrap_or("default")
+            .to_string();
+        if let Some(metadata) = object.get_mut("metadata").and_then(Value::as_object_mut) {
+            metadata.insert("namespace".to_string(), Value::String(namespace.clone()));
+        }
+        (
+            Api::namespaced_with(client.clone(), &namespace, &ar),
+            Some(namespace),
+        )
+    } else {
+        (Api::all_with(client.clone(), &ar), None)
+    };
+
+    // Server-side apply rejects requests that set managedFields.
+    if let Some(metadata) = object.get_mut("metadata").and_then(Value::as_object_mut) {
+        metadata.remove("managedFields");
+    }
+
+    Ok(ResolvedDocument {
+        api,
+        api_version,
+        kind,
+        name,
+        namespace,
+        detail_kind,
+        object,
+    })
+}
+
+/// Removes fields that change on every write and would only add noise to a diff.
+pub(crate) fn strip_noisy_fields(object: &mut Value) {
+    if let Some(map) = object.as_object_mut() {
+        map.remove("status");
+    }
+    if let Some(metadata) = object.get_mut("metadata").and_then(Value::as_object_mut) {
+        for field in [
+            "managedFields",
+            "resourceVersion",
+            "generation",
+            "uid",
+            "creationTimestamp",
+            "selfLink",
+        ] {
+            metadata.remove(field);
+        }
+    }
+}
+
+pub(crate) async fn server_side_apply(
+    document: &ResolvedDocument,
+    force: bool,
+    dry_run: bool,
+) -> std::result::Result<DynamicObject, kube::Error> {
+    let mut params = PatchParams::apply(FIELD_MANAGER);
+    params.force = force;
+    params.dry_run = dry_run;
+    document
+        .api
+        .patch(&document.name, &params, &Patch::Apply(&document.object))
+        .await
+}
+
+fn document_identity(object: &Value) -> (String, String, String, Option<String>) {
+    let field = |pointer: &str| {
+        object
+            .pointer(pointer)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    (
+        field("/apiVersion").unwrap_or_default(),
+        field("/kind").unwrap_or_default(),
+        field("/metadata/name").unwrap_or_default(),
+        field("/metadata/namespace"),
+    )
+}
+
+#[tauri::command]
+pub async fn apply_resource_yaml(
+    client_pool: tauri::State<'_, K8sClientPool>,
+    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    yaml: String,
+    namespace: Option<String>,
+    force: Option<bool>,
+    dry_run: Option<bool>,
+) -> Result<Vec<ApplyResult>> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_raw_client(&client_pool, context, kc_path).await?;
+    let documents = parse_yaml_documents(&yaml)?;
+    if documents.is_empty() {
+        return Err(bad_request("No resources found in YAML".to_string()));
+    }
+
+    let force = force.unwrap_or(false);
+    let dry_run = dry_run.unwrap_or(false);
+    let mut results = Vec::with_capacity(documents.len());
+    for object in documents {
+        let (api_version, kind, name, object_namespace) = document_identity(&object);
+        let document = match resolve_document(&client, object, namespace.as_deref()).await {
+            Ok(document) => document,
+            Err(e) => {
+                results.push(ApplyResult {
+                    api_version,
+                    kind,
+                    name,
+                    namespace: object_namespace,
+                    status: "error".to_string(),
+                    message: Some(e.to_string()),
+                    resource: None,
+                });
+                continue;
+            }
+        };
+
+        let (status, message, resource) = match server_side_apply(&document, force, dry_run).await {
+            Ok(applied) => ("applied", None, Some(serde_json::to_value(applied)?)),
+            Err(kube::Error::Api(response)) if response.code == 409 => {
+                ("conflict", Some(response.message), None)
+            }
+            Err(e) => ("error", Some(e.to_string()), None),
+        };
+        log::info!(
+            "Apply {} {} ({}, dry_run={}): {}",
+            document.kind,
+            document.name,
+            document.namespace.as_deref().unwrap_or("cluster"),
+            dry_run,
+            status
+        );
+        results.push(ApplyResult {
+            api_version: document.api_version,
+            kind: document.kind,
+            name: document.name,
+            namespace: document.namespace,
+            status: status.to_string(),
+            message,
+            resource,
+        });
+    }
+    Ok(results)
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiffLine {
+    /// "equal", "insert" or "delete"
+    pub tag: String,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResourceDiff {
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    /// Whether the object already exists on the cluster
+    pub exists: bool,
+    pub changed: bool,
+    /// RFC 6902 patch that turns the live object into the applied one
+    pub patch: Value,
+    pub lines: Vec<DiffLine>,
+    pub error: Option<String>,
+}
+
+/// Line diff of two objects rendered as YAML.
+pub(crate) fn diff_lines(old: &Value, new: &Value) -> Result<Vec<DiffLine>> {
+    let render = |value: &Value| -> Result<String> {
+        if value.is_null() {
+            Ok(String::new())
+        } else {
+            Ok(serde_yml::to_string(value)?)
+        }
+    };
+    let (old, new) = (render(old)?, render(new)?);
+    Ok(similar::TextDiff::from_lines(&old, &new)
+        .iter_all_changes()
+        .map(|change| DiffLine {
+            tag: match change.tag() {
+                similar::ChangeTag::Equal => "equal",
+                similar::ChangeTag::Insert => "insert",
+                similar::ChangeTag::Delete => "delete",
+            }
+            .to_string(),
+            text: change
+                .as_str()
+                .unwrap_or_default()
+                .trim_end_matches('\n')
+                .to_string(),
+        })
+        .collect())
+}
+
+async fn diff_document(
+    k8s_client: &dyn K8sClient,
+    document: &ResolvedDocument,
+    force: bool,
+) -> Result<(bool, Value, Value)> {
+    let live = match fetch_resource_detail(
+        k8s_client,
+        &document.detail_kind,
+        &document.name,
+        document.namespace.as_deref(),
+    )
+    .await
+    {
+        Ok(live) => Some(live),
+        Err(K8sError::Kube(kube::Error::Api(response))) if response.code == 404 => None,
+        Err(e) => return Err(e),
+    };
+    let mut applied = serde_json::to_value(server_side_apply(document, force, true).await?)?;
+    strip_noisy_fields(&mut applied);
+    let exists = live.is_some();
+    let mut live = live.unwrap_or(Value::Null);
+    strip_noisy_fields(&mut live);
+    Ok((exists, live, applied))
+}
+
+#[tauri::command]
+pub async fn diff_resource(
+    client_pool: tauri::State<'_, K8sClientPool>,
+    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    yaml: String,
+    namespace: Option<String>,
+    force: Option<bool>,
+) -> Result<Vec<ResourceDiff>> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let k8s_client = get_or_create_client(&client_pool, context.clone(), kc_path.clone()).await?;
+    let client = get_or_create_raw_client(&client_pool, context, kc_path).await?;
+    let documents = parse_yaml_documents(&yaml)?;
+    if documents.is_empty() {
+        return Err(bad_request("No resources found in YAML".to_string()));
+    }
+
+    let force = force.unwrap_or(false);
+    let mut results = Vec::with_capacity(documents.len());
+    for object in documents {
+        let (api_version, kind, name, object_namespace) = document_identity(&object);
+        let mut diff = ResourceDiff {
+            api_version,
+            kind,
+            name,
+            namespace: object_namespace,
+            exists: false,
+            changed: false,
+            patch: Value::Array(vec![]),
+            lines: vec![],
+            error: None,
+        };
+
+        let outcome = match resolve_document(&client, object, namespace.as_deref()).await {
+            Ok(document) => {
+                diff.namespace = document.namespace.clone();
+                diff_document(k8s_client.as_ref(), &document, force).await
+            }
+            Err(e) => Err(e),
+        };
+        match outcome {
+            Ok((exists, live, applied)) => {
+                let patch = json_patch::diff(&live, &applied);
+                diff.exists = exists;
+                diff.changed = !patch.0.is_empty();
+                diff.patch = serde_json::to_value(patch)?;
+                diff.lines = diff_lines(&live, &applied)?;
+            }
+            Err(e) => diff.error = Some(e.to_string()),
+        }
+        results.push(diff);
+    }
+    Ok(results)
+}
@@ -0,0 +1,126 @@
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use tauri::State;
+
+use crate::k8s_api::{
+    bad_request, get_or_create_client_for_app, require_namespace, DeleteOptions, K8sClientPool,
+    Result,
+};
+
+/// Upper bound on requests a bulk operation has in flight at once
+const BULK_CONCURRENCY: usize = 8;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResourceTarget {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkResult {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Runs `op` for every target with bounded concurrency. A failing target does
+/// not stop the others; results are reported in the order of `targets`.
+async fn run_bulk<F, Fut>(targets: Vec<ResourceTarget>, op: F) -> Vec<BulkResult>
+where
+    F: Fn(ResourceTarget) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    stream::iter(targets)
+        .map(|target| {
+            let fut = op(target.clone());
+            async move {
+                let outcome = fut.await;
+                if let Err(e) = &outcome {
+                    log::warn!(
+                        "Bulk operation failed for {} {:?}/{}: {}",
+                        target.kind,
+                        target.namespace,
+                        target.name,
+                        e
+                    );
+                }
+                BulkResult {
+                    kind: target.kind,
+                    name: target.name,
+                    namespace: target.namespace,
+                    success: outcome.is_ok(),
+                    error: outcome.err().map(|e| e.to_string()),
+                }
+            }
+        })
+        .buffered(BULK_CONCURRENCY)
+        .collect()
+        .await
+}
+
+#[tauri::command]
+pub async fn bulk_delete_resources(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    targets: Vec<ResourceTarget>,
+    options: Option<DeleteOptions>,
+) -> Result<Vec<BulkResult>> {
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    let options = options.unwrap_or_default();
+    let client = client.as_ref();
+    let options = &options;
+    Ok(run_bulk(targets, |t| async move {
+        client
+            .delete_resource(&t.kind, &t.name, t.namespace.as_deref(), options)
+            .await
+    })
+    .await)
+}
+
+#[tauri::command]
+pub async fn bulk_rollout_restart(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    targets: Vec<ResourceTarget>,
+) -> Result<Vec<BulkResult>> {
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    let client = client.as_ref();
+    Ok(run_bulk(targets, |t| async move {
+        let ns = t
+            .namespace
+            .as_deref()
+            .ok_or_else(|| require_namespace(&t.kind))?;
+        client.rollout_restart(&t.kind, &t.name, ns).await
+    })
+    .await)
+}
+
+#[tauri::command]
+pub async fn bulk_scale_resources(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    targets: Vec<ResourceTarget>,
+    replicas: i32,
+) -> Result<Vec<BulkResult>> {
+    if replicas < 0 {
+        return Err(bad_request(format!(
+            "Replicas must not be negative: {}",
+            replicas
+        )));
+    }
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    let client = client.as_ref();
+    Ok(run_bulk(targets, |t| async move {
+        client
+            .scale(&t.kind, &t.name, t.namespace.as_deref(), replicas)
+            .await
+    })
+    .await)
+}
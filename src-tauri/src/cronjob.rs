@@ -0,0 +1,149 @@
+use k8s_openapi::api::batch::v1::Job;
+use serde::Serialize;
+use tauri::State;
+
+use crate::k8s_api::{
+    get_or_create_client_for_app, K8sClientPool, ListQuery, Result, MANUAL_INSTANTIATE_ANNOTATION,
+};
+
+/// Number of runs `get_cronjob_runs` returns when the caller does not pass a limit
+const DEFAULT_RUN_LIMIT: usize = 5;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CronJobRun {
+    /// Name of the Job
+    pub name: String,
+    /// Running, Succeeded or Failed
+    pub status: String,
+    /// Whether the Job was created by hand rather than by the schedule
+    pub manual: bool,
+    #[serde(rename = "startTime")]
+    pub start_time: Option<String>,
+    #[serde(rename = "completionTime")]
+    pub completion_time: Option<String>,
+    #[serde(rename = "durationSeconds")]
+    pub duration_seconds: Option<i64>,
+    pub succeeded: i32,
+    pub failed: i32,
+}
+
+fn job_status(job: &Job) -> &'static str {
+    let condition = |type_: &str| {
+        job.status
+            .as_ref()
+            .and_then(|s| s.conditions.as_ref())
+            .is_some_and(|conditions| {
+                conditions
+                    .iter()
+                    .any(|c| c.type_ == type_ && c.status == "True")
+            })
+    };
+    if condition("Complete") {
+        "Succeeded"
+    } else if condition("Failed") {
+        "Failed"
+    } else {
+        "Running"
+    }
+}
+
+fn cronjob_run(job: &Job) -> CronJobRun {
+    let status = job.status.clone().unwrap_or_default();
+    let start = status.start_time.map(|t| t.0);
+    let completion = status.completion_time.map(|t| t.0);
+    CronJobRun {
+        name: job.metadata.name.clone().unwrap_or_default(),
+        status: job_status(job).to_string(),
+        manual: job
+            .metadata
+            .annotations
+            .as_ref()
+            .and_then(|a| a.get(MANUAL_INSTANTIATE_ANNOTATION))
+            .is_some_and(|v| v == "manual"),
+        start_time: start.map(|t| t.to_rfc3339()),
+        completion_time: completion.map(|t| t.to_rfc3339()),
+        duration_seconds: start
+            .zip(completion)
+            .map(|(start, end)| (end - start).num_seconds()),
+        succeeded: status.succeeded.unwrap_or(0),
+        failed: status.failed.unwrap_or(0),
+    }
+}
+
+/// Runs a CronJob now by creating a Job from its jobTemplate. Returns the
+/// name of the created Job.
+#[tauri::command]
+pub async fn trigger_cronjob(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
+    namespace: String,
+) -> Result<String> {
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    let job = client.trigger_cronjob(&name, &namespace).await?;
+    log::info!("Triggered CronJob {}/{} as Job {}", namespace, name, job);
+    Ok(job)
+}
+
+#[tauri::command]
+pub async fn suspend_cronjob(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
+    namespace: String,
+) -> Result<()> {
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    client.set_cronjob_suspended(&name, &namespace, true).await
+}
+
+#[tauri::command]
+pub async fn resume_cronjob(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
+    namespace: String,
+) -> Result<()> {
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    client.set_cronjob_suspended(&name, &namespace, false).await
+}
+
+/// Summarizes the most recent Jobs owned by a CronJob, newest first.
+#[tauri::command]
+pub async fn get_cronjob_runs(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
+    namespace: String,
+    limit: Option<usize>,
+) -> Result<Vec<CronJobRun>> {
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    let cronjob = client.get_cronjob(&name, &namespace).await?;
+    let uid = cronjob.metadata.uid.unwrap_or_default();
+    let mut jobs: Vec<Job> = client
+        .list_jobs(Some(&namespace), &ListQuery::default())
+        .await?
+        .items
+        .into_iter()
+        .filter(|job| {
+            job.metadata
+                .owner_references
+                .iter()
+                .flatten()
+                .any(|o| o.controller == Some(true) && o.uid == uid)
+        })
+        .collect();
+    jobs.sort_by(|a, b| {
+        b.metadata
+            .creation_timestamp
+            .cmp(&a.metadata.creation_timestamp)
+    });
+    Ok(jobs
+        .iter()
+        .take(limit.unwrap_or(DEFAULT_RUN_LIMIT))
+        .map(cronjob_run)
+        .collect())
+}
@@ -0,0 +1,318 @@
+use futures::future::join_all;
+use k8s_openapi::api::core::v1::Pod;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, State};
+use uuid::Uuid;
+
+use crate::k8s_api::{
+    get_or_create_client, DeleteOptions, K8sClient, K8sClientPool, K8sError, ListQuery, Result,
+};
+
+/// How long to wait before retrying an eviction refused by a PodDisruptionBudget
+const EVICTION_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+/// How often to check whether an evicted pod is gone
+const DELETION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const MIRROR_POD_ANNOTATION: &str = "kubernetes.io/config.mirror";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DrainOptions {
+    /// Overrides each pod's terminationGracePeriodSeconds
+    #[serde(rename = "gracePeriodSeconds")]
+    pub grace_period_seconds: Option<u32>,
+    /// Gives up on the drain after this many seconds; waits indefinitely when unset
+    #[serde(rename = "timeoutSeconds")]
+    pub timeout_seconds: Option<u64>,
+    /// Evict pods using emptyDir volumes, whose data is lost when the pod goes away
+    #[serde(rename = "deleteEmptyDirData", default)]
+    pub delete_empty_dir_data: bool,
+    /// Evict pods not managed by a controller, which nothing will recreate
+    #[serde(default)]
+    pub force: bool,
+}
+
+pub type NodeDrains = Arc<Mutex<HashMap<String, tokio::task::JoinHandle<()>>>>;
+
+#[derive(Clone, Serialize)]
+struct NodeDrainEvent {
+    /// cordoned, skipped, evicting, blocked, evicted, failed, completed or error
+    event_type: String,
+    pod: Option<String>,
+    namespace: Option<String>,
+    message: String,
+}
+
+#[derive(Clone)]
+struct DrainEmitter {
+    app: AppHandle,
+    event_name: String,
+}
+
+impl DrainEmitter {
+    fn emit(&self, event_type: &str, pod: Option<&Pod>, message: String) {
+        let _ = self.app.emit(
+            &self.event_name,
+            NodeDrainEvent {
+                event_type: event_type.to_string(),
+                pod: pod.and_then(|p| p.metadata.name.clone()),
+                namespace: pod.and_then(|p| p.metadata.namespace.clone()),
+                message,
+            },
+        );
+    }
+}
+
+/// Returns why a pod is left alone by a drain, matching `kubectl drain
+/// --ignore-daemonsets`: mirror pods are owned by the kubelet and DaemonSet
+/// pods would be recreated on the same node immediately.
+fn skip_reason(pod: &Pod) -> Option<&'static str> {
+    if pod
+        .metadata
+        .annotations
+        .as_ref()
+        .is_some_and(|a| a.contains_key(MIRROR_POD_ANNOTATION))
+    {
+        return Some("mirror pod");
+    }
+    let daemonset_owned = pod
+        .metadata
+        .owner_references
+        .iter()
+        .flatten()
+        .any(|o| o.controller == Some(true) && o.kind == "DaemonSet");
+    daemonset_owned.then_some("managed by a DaemonSet")
+}
+
+/// Succeeded and Failed pods have no running containers left whose data
+/// could be lost
+fn is_terminal(pod: &Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|status| status.phase.as_deref())
+        .is_some_and(|phase| phase == "Succeeded" || phase == "Failed")
+}
+
+/// Pods without a controller are gone for good once evicted
+fn is_unmanaged(pod: &Pod) -> bool {
+    !pod.metadata
+        .owner_references
+        .iter()
+        .flatten()
+        .any(|o| o.controller == Some(true))
+}
+
+fn uses_empty_dir(pod: &Pod) -> bool {
+    pod.spec
+        .as_ref()
+        .and_then(|spec| spec.volumes.as_ref())
+        .is_some_and(|volumes| volumes.iter().any(|v| v.empty_dir.is_some()))
+}
+
+fn pod_label(pod: &Pod) -> String {
+    format!(
+        "{}/{}",
+        pod.metadata.namespace.as_deref().unwrap_or_default(),
+        pod.metadata.name.as_deref().unwrap_or_default()
+    )
+}
+
+/// Evicts one pod and waits for it to go away. Evictions that would violate a
+/// PodDisruptionBudget are answered with 429 and retried until the drain times
+/// out or is stopped.
+async fn drain_pod(
+    client: &dyn K8sClient,
+    pod: Pod,
+    options: &DeleteOptions,
+    emitter: &DrainEmitter,
+) -> std::result::Result<(), String> {
+    let name = pod.metadata.name.clone().unwrap_or_default();
+    let namespace = pod.metadata.namespace.clone().unwrap_or_default();
+
+    emitter.emit("evicting", Some(&pod), String::new());
+    loop {
+        match client.evict_pod(&name, &namespace, options).await {
+            Ok(()) => break,
+            Err(K8sError::Kube(kube::Error::Api(e))) if e.code == 404 => {
+                emitter.emit("evicted", Some(&pod), "Pod was already gone".to_string());
+                return Ok(());
+            }
+            Err(K8sError::EvictionRefused(message)) => {
+                emitter.emit("blocked", Some(&pod), message);
+                tokio::time::sleep(EVICTION_RETRY_INTERVAL).await;
+            }
+            Err(e) => {
+                let message = e.to_string();
+                emitter.emit("failed", Some(&pod), message.clone());
+                return Err(message);
+            }
+        }
+    }
+
+    // A pod with the same name but a new UID is a replacement, not the evicted pod
+    loop {
+        match client.get_pod(&name, &namespace).await {
+            Ok(current) if current.metadata.uid == pod.metadata.uid => {
+                tokio::time::sleep(DELETION_POLL_INTERVAL).await;
+            }
+            Ok(_) => break,
+            Err(K8sError::Kube(kube::Error::Api(e))) if e.code == 404 => break,
+            Err(e) => {
+                let message = e.to_string();
+                emitter.emit("failed", Some(&pod), message.clone());
+                return Err(message);
+            }
+        }
+    }
+    emitter.emit("evicted", Some(&pod), String::new());
+    Ok(())
+}
+
+async fn run_drain(
+    client: &dyn K8sClient,
+    node: &str,
+    options: &DrainOptions,
+    emitter: &DrainEmitter,
+) -> std::result::Result<String, String> {
+    client
+        .set_node_unschedulable(node, true)
+        .await
+        .map_err(|e| format!("Failed to cordon node {}: {}", node, e))?;
+    emitter.emit(
+        "cordoned",
+        None,
+        format!("Node {} marked unschedulable", node),
+    );
+
+    let query = ListQuery::new(None, Some(format!("spec.nodeName={}", node)));
+    let pods = client
+        .list_pods(None, &query)
+        .await
+        .map_err(|e| format!("Failed to list pods on node {}: {}", node, e))?;
+
+    let mut targets = Vec::new();
+    for pod in pods.items {
+        match skip_reason(&pod) {
+            Some(reason) => emitter.emit("skipped", Some(&pod), reason.to_string()),
+            None => targets.push(pod),
+        }
+    }
+
+    if !options.delete_empty_dir_data {
+        let with_empty_dir: Vec<String> = targets
+            .iter()
+            .filter(|p| !is_terminal(p) && uses_empty_dir(p))
+            .map(pod_label)
+            .collect();
+        if !with_empty_dir.is_empty() {
+            return Err(format!(
+                "Pods with emptyDir volumes would lose their data: {}. Enable deleting emptyDir data to continue",
+                with_empty_dir.join(", ")
+            ));
+        }
+    }
+
+    // kubectl drain refuses these unless --force is given
+    if !options.force {
+        let unmanaged: Vec<String> = targets
+            .iter()
+            .filter(|p| !is_terminal(p) && is_unmanaged(p))
+            .map(pod_label)
+            .collect();
+        if !unmanaged.is_empty() {
+            return Err(format!(
+                "Pods not managed by a controller would not be recreated: {}. Enable force to continue",
+                unmanaged.join(", ")
+            ));
+        }
+    }
+
+    let delete_options = DeleteOptions {
+        grace_period_seconds: options.grace_period_seconds,
+        ..Default::default()
+    };
+    let total = targets.len();
+    let evictions = join_all(
+        targets
+            .into_iter()
+            .map(|pod| drain_pod(client, pod, &delete_options, emitter)),
+    );
+    let results = match options.timeout_seconds {
+        Some(secs) => tokio::time::timeout(Duration::from_secs(secs), evictions)
+            .await
+            .map_err(|_| format!("Drain of node {} timed out after {}s", node, secs))?,
+        None => evictions.await,
+    };
+
+    let failed = results.iter().filter(|r| r.is_err()).count();
+    if failed > 0 {
+        return Err(format!(
+            "Failed to evict {} of {} pods from node {}",
+            failed, total, node
+        ));
+    }
+    Ok(format!("Evicted {} pods from node {}", total, node))
+}
+
+/// Cordons a node and evicts its pods through the Eviction API. Progress is
+/// emitted as `node-drain-{drainId}` events; the drain ends with a `completed`
+/// or `error` event and can be cancelled with `stop_node_drain`.
+#[tauri::command]
+pub async fn drain_node(
+    app: AppHandle,
+    drains: State<'_, NodeDrains>,
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
+    options: DrainOptions,
+) -> Result<String> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_client(&client_pool, context, kc_path).await?;
+
+    let drain_id = Uuid::new_v4().to_string();
+    log::info!("Starting drain of node {}, drain_id: {}", name, drain_id);
+
+    let emitter = DrainEmitter {
+        app: app.clone(),
+        event_name: format!("node-drain-{}", drain_id),
+    };
+    let drains_handle = drains.inner().clone();
+    let id = drain_id.clone();
+    // Hold the registry lock while spawning so a drain that finishes at once
+    // cannot try to remove itself before it is registered
+    let mut registry = drains.lock().map_err(|e| K8sError::Lock(e.to_string()))?;
+    let handle = tokio::spawn(async move {
+        match run_drain(client.as_ref(), &name, &options, &emitter).await {
+            Ok(message) => emitter.emit("completed", None, message),
+            Err(message) => {
+                log::warn!("Drain of node {} failed: {}", name, message);
+                emitter.emit("error", None, message);
+            }
+        }
+        if let Ok(mut drains) = drains_handle.lock() {
+            drains.remove(&id);
+        }
+    });
+
+    registry.insert(drain_id.clone(), handle);
+    Ok(drain_id)
+}
+
+/// Stops a running drain. Evictions already accepted by the API server are not
+/// undone and the node stays cordoned.
+#[tauri::command]
+pub async fn stop_node_drain(drains: State<'_, NodeDrains>, drain_id: String) -> Result<()> {
+    if let Some(handle) = drains
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .remove(&drain_id)
+    {
+        handle.abort();
+    }
+    Ok(())
+}
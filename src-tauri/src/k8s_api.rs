@@ -1,5 +1,6 @@
 use async_trait::async_trait;
-use futures::StreamExt;
+use futures::stream::BoxStream;
+use futures::{AsyncBufReadExt, StreamExt};
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
 use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
 use k8s_openapi::api::batch::v1::{CronJob, Job};
@@ -8,26 +9,35 @@ use k8s_openapi::api::core::v1::{
     PersistentVolumeClaim, Pod, ResourceQuota, Secret, Service, ServiceAccount,
 };
 use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding};
 use k8s_openapi::api::storage::v1::StorageClass;
 use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
 use kube::{
     api::{
-        Api, ApiResource, DeleteParams, DynamicObject, ListParams, ObjectList, Patch, PatchParams,
+        Api, DeleteParams, DynamicObject, EvictParams, ListParams, LogParams, ObjectList, Patch,
+        PatchParams, PostParams, PropagationPolicy,
     },
     config::{Config, InferConfigError, KubeConfigOptions, Kubeconfig, KubeconfigError},
-    runtime::watcher,
+    runtime::{reflector, watcher, WatchStreamExt},
     Client,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::env;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 use thiserror::Error;
 
+use crate::api_discovery::{
+    discover_api_resources, dynamic_api_resource, resolve_kind, ApiResourceInfo,
+};
+use crate::resource_cache::{CacheKey, ResourceCache, SharedWatch, Subscribers};
+
 const CLIENT_CACHE_TTL: Duration = Duration::from_secs(300);
 
 #[derive(Hash, Eq, PartialEq, Clone)]
@@ -36,8 +46,12 @@ pub(crate) struct ClientCacheKey {
     kubeconfig_path: Option<String>,
 }
 
+/// Discovery results are cached alongside the client they were fetched with.
+type DiscoveryCell = Arc<tokio::sync::OnceCell<Arc<Vec<ApiResourceInfo>>>>;
+
 pub(crate) struct CachedClient {
     client: Client,
+    discovery: DiscoveryCell,
     created_at: Instant,
 }
 
@@ -53,10 +67,14 @@ pub enum K8sError {
     KubeconfigError(#[from] KubeconfigError),
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yml::Error),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     #[error("Lock error: {0}")]
     Lock(String),
+    #[error("Eviction refused: {0}")]
+    EvictionRefused(String),
 }
 
 impl serde::Serialize for K8sError {
@@ -85,6 +103,184 @@ pub struct CrdGroup {
     pub resources: Vec<CrdResourceInfo>,
 }
 
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PodLogOptions {
+    pub container: Option<String>,
+    #[serde(default)]
+    pub follow: bool,
+    #[serde(rename = "tailLines")]
+    pub tail_lines: Option<i64>,
+    #[serde(rename = "sinceSeconds")]
+    pub since_seconds: Option<i64>,
+    #[serde(default)]
+    pub timestamps: bool,
+    #[serde(default)]
+    pub previous: bool,
+}
+
+impl PodLogOptions {
+    fn to_log_params(&self) -> LogParams {
+        LogParams {
+            container: self.container.clone(),
+            follow: self.follow,
+            previous: self.previous,
+            since_seconds: self.since_seconds,
+            tail_lines: self.tail_lines,
+            timestamps: self.timestamps,
+            ..Default::default()
+        }
+    }
+}
+
+/// A container of a workload's pod template and its image.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContainerImage {
+    pub name: String,
+    pub image: String,
+    /// Whether this is an init container
+    pub init: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DeleteOptions {
+    #[serde(rename = "gracePeriodSeconds")]
+    pub grace_period_seconds: Option<u32>,
+    /// "Foreground", "Background" or "Orphan"
+    #[serde(rename = "propagationPolicy")]
+    pub propagation_policy: Option<String>,
+    #[serde(rename = "dryRun", default)]
+    pub dry_run: bool,
+    /// Deletes immediately, skipping graceful termination
+    #[serde(default)]
+    pub force: bool,
+}
+
+impl DeleteOptions {
+    pub(crate) fn to_delete_params(&self) -> Result<DeleteParams> {
+        let propagation_policy = match self.propagation_policy.as_deref() {
+            None => None,
+            Some("Foreground") => Some(PropagationPolicy::Foreground),
+            Some("Background") => Some(PropagationPolicy::Background),
+            Some("Orphan") => Some(PropagationPolicy::Orphan),
+            Some(other) => {
+                return Err(bad_request(format!(
+                    "Invalid propagation policy: {}",
+                    other
+                )))
+            }
+        };
+        Ok(DeleteParams {
+            dry_run: self.dry_run,
+            grace_period_seconds: if self.force {
+                Some(0)
+            } else {
+                self.grace_period_seconds
+            },
+            propagation_policy,
+            ..Default::default()
+        })
+    }
+}
+
+/// Server-side filters shared by list and watch requests.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ListQuery {
+    #[serde(rename = "labelSelector")]
+    pub label_selector: Option<String>,
+    #[serde(rename = "fieldSelector")]
+    pub field_selector: Option<String>,
+    /// Maximum number of items to return; the rest is fetched with `continue_token`
+    pub limit: Option<u32>,
+    #[serde(rename = "continue")]
+    pub continue_token: Option<String>,
+}
+
+impl ListQuery {
+    pub fn new(label_selector: Option<String>, field_selector: Option<String>) -> Self {
+        Self {
+            label_selector: label_selector.filter(|s| !s.trim().is_empty()),
+            field_selector: field_selector.filter(|s| !s.trim().is_empty()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_page(mut self, limit: Option<u32>, continue_token: Option<String>) -> Self {
+        self.limit = limit.filter(|l| *l > 0);
+        self.continue_token = continue_token.filter(|c| !c.is_empty());
+        self
+    }
+
+    pub(crate) fn to_list_params(&self) -> ListParams {
+        ListParams {
+            label_selector: self.label_selector.clone(),
+            field_selector: self.field_selector.clone(),
+            limit: self.limit,
+            continue_token: self.continue_token.clone(),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn to_watcher_config(&self) -> watcher::Config {
+        watcher::Config {
+            label_selector: self.label_selector.clone(),
+            field_selector: self.field_selector.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// One page of a list request. `continue_token` is set when more items remain.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourcePage<T> {
+    pub items: Vec<T>,
+    #[serde(rename = "continue")]
+    pub continue_token: Option<String>,
+    #[serde(rename = "remainingItemCount")]
+    pub remaining_item_count: Option<i64>,
+    /// Printer columns when the page was requested as a server-side Table;
+    /// `items` are then the table rows
+    #[serde(rename = "columnDefinitions")]
+    pub column_definitions: Option<Vec<Value>>,
+}
+
+impl<T> Default for ResourcePage<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            continue_token: None,
+            remaining_item_count: None,
+            column_definitions: None,
+        }
+    }
+}
+
+impl<T> ResourcePage<T> {
+    pub(crate) fn map_items<U>(self, f: impl FnOnce(Vec<T>) -> Vec<U>) -> ResourcePage<U> {
+        ResourcePage {
+            items: f(self.items),
+            continue_token: self.continue_token,
+            remaining_item_count: self.remaining_item_count,
+            column_definitions: self.column_definitions,
+        }
+    }
+}
+
+impl<T: Clone> From<ObjectList<T>> for ResourcePage<T> {
+    fn from(list: ObjectList<T>) -> Self {
+        Self {
+            items: list.items,
+            continue_token: list.metadata.continue_.filter(|c| !c.is_empty()),
+            remaining_item_count: list.metadata.remaining_item_count,
+            column_definitions: None,
+        }
+    }
+}
+
+/// Accept header asking the API server for kubectl's printer columns
+const TABLE_ACCEPT: &str = "application/json;as=Table;g=meta.k8s.io;v=v1";
+
+pub type LogLineStream = BoxStream<'static, Result<String>>;
+
 macro_rules! define_k8s_trait {
     (
         namespaced: [$(($NType:ty, $n_list_fn:ident, $n_get_fn:ident)),* $(,)?],
@@ -93,14 +289,14 @@ macro_rules! define_k8s_trait {
         #[async_trait]
         pub trait K8sClient: Send + Sync {
             $(
-                async fn $n_list_fn(&self, namespace: Option<&str>) -> Result<Vec<$NType>>;
+                async fn $n_list_fn(&self, namespace: Option<&str>, query: &ListQuery) -> Result<ResourcePage<$NType>>;
                 async fn $n_get_fn(&self, name: &str, namespace: &str) -> Result<$NType>;
             )*
             $(
-                async fn $c_list_fn(&self) -> Result<Vec<$CType>>;
+                async fn $c_list_fn(&self, query: &ListQuery) -> Result<ResourcePage<$CType>>;
                 async fn $c_get_fn(&self, name: &str) -> Result<$CType>;
             )*
-            async fn list_crds(&self) -> Result<Vec<CustomResourceDefinition>>;
+            async fn list_crds(&self, query: &ListQuery) -> Result<ResourcePage<CustomResourceDefinition>>;
             async fn list_custom_resources(
                 &self,
                 group: &str,
@@ -108,7 +304,8 @@ macro_rules! define_k8s_trait {
                 plural: &str,
                 scope: &str,
                 namespace: Option<&str>,
-            ) -> Result<Vec<Value>>;
+                query: &ListQuery,
+            ) -> Result<ResourcePage<Value>>;
             async fn get_custom_resource(
                 &self,
                 group: &str,
@@ -118,9 +315,59 @@ macro_rules! define_k8s_trait {
                 name: &str,
                 namespace: Option<&str>,
             ) -> Result<Value>;
+            async fn list_table(
+                &self,
+                kind: &str,
+                namespace: Option<&str>,
+                query: &ListQuery,
+            ) -> Result<ResourcePage<Value>>;
+            async fn list_metadata(
+                &self,
+                kind: &str,
+                namespace: Option<&str>,
+                query: &ListQuery,
+            ) -> Result<ResourcePage<ObjectMeta>>;
             async fn apiserver_version(&self) -> Result<k8s_openapi::apimachinery::pkg::version::Info>;
-            async fn delete_resource(&self, kind: &str, name: &str, namespace: Option<&str>) -> Result<()>;
-            async fn rollout_restart_deployment(&self, name: &str, namespace: &str) -> Result<()>;
+            async fn api_resources(&self) -> Result<Arc<Vec<ApiResourceInfo>>>;
+            async fn delete_resource(
+                &self,
+                kind: &str,
+                name: &str,
+                namespace: Option<&str>,
+                options: &DeleteOptions,
+            ) -> Result<()>;
+            async fn evict_pod(&self, name: &str, namespace: &str, options: &DeleteOptions) -> Result<()>;
+            async fn rollout_restart(&self, kind: &str, name: &str, namespace: &str) -> Result<()>;
+            async fn set_rollout_paused(&self, name: &str, namespace: &str, paused: bool) -> Result<()>;
+            async fn set_rollout_partition(
+                &self,
+                name: &str,
+                namespace: &str,
+                partition: i32,
+            ) -> Result<()>;
+            async fn set_image(
+                &self,
+                kind: &str,
+                name: &str,
+                namespace: &str,
+                images: &[ContainerImage],
+            ) -> Result<()>;
+            async fn trigger_cronjob(&self, name: &str, namespace: &str) -> Result<String>;
+            async fn set_cronjob_suspended(&self, name: &str, namespace: &str, suspended: bool) -> Result<()>;
+            async fn set_node_unschedulable(&self, name: &str, unschedulable: bool) -> Result<()>;
+            async fn scale(
+                &self,
+                kind: &str,
+                name: &str,
+                namespace: Option<&str>,
+                replicas: i32,
+            ) -> Result<()>;
+            async fn stream_pod_logs(
+                &self,
+                name: &str,
+                namespace: &str,
+                options: &PodLogOptions,
+            ) -> Result<LogLineStream>;
         }
     };
 }
@@ -133,14 +380,14 @@ macro_rules! define_k8s_impl {
         #[async_trait]
         impl K8sClient for RealK8sClient {
             $(
-                async fn $n_list_fn(&self, namespace: Option<&str>) -> Result<Vec<$NType>> {
+                async fn $n_list_fn(&self, namespace: Option<&str>, query: &ListQuery) -> Result<ResourcePage<$NType>> {
                     let api: Api<$NType> = if let Some(ns) = namespace {
                         Api::namespaced(self.client.clone(), ns)
                     } else {
                         Api::all(self.client.clone())
                     };
-                    let items: ObjectList<$NType> = api.list(&ListParams::default()).await?;
-                    Ok(items.items)
+                    let items: ObjectList<$NType> = api.list(&query.to_list_params()).await?;
+                    Ok(items.into())
                 }
 
                 async fn $n_get_fn(&self, name: &str, namespace: &str) -> Result<$NType> {
@@ -149,10 +396,10 @@ macro_rules! define_k8s_impl {
                 }
             )*
             $(
-                async fn $c_list_fn(&self) -> Result<Vec<$CType>> {
+                async fn $c_list_fn(&self, query: &ListQuery) -> Result<ResourcePage<$CType>> {
                     let api: Api<$CType> = Api::all(self.client.clone());
-                    let items: ObjectList<$CType> = api.list(&ListParams::default()).await?;
-                    Ok(items.items)
+                    let items: ObjectList<$CType> = api.list(&query.to_list_params()).await?;
+                    Ok(items.into())
                 }
 
                 async fn $c_get_fn(&self, name: &str) -> Result<$CType> {
@@ -161,10 +408,10 @@ macro_rules! define_k8s_impl {
                 }
             )*
 
-            async fn list_crds(&self) -> Result<Vec<CustomResourceDefinition>> {
+            async fn list_crds(&self, query: &ListQuery) -> Result<ResourcePage<CustomResourceDefinition>> {
                 let api: Api<CustomResourceDefinition> = Api::all(self.client.clone());
-                let items: ObjectList<CustomResourceDefinition> = api.list(&ListParams::default()).await?;
-                Ok(items.items)
+                let items: ObjectList<CustomResourceDefinition> = api.list(&query.to_list_params()).await?;
+                Ok(items.into())
             }
 
             async fn list_custom_resources(
@@ -174,14 +421,9 @@ macro_rules! define_k8s_impl {
                 plural: &str,
                 scope: &str,
                 namespace: Option<&str>,
-            ) -> Result<Vec<Value>> {
-                let ar = ApiResource {
-                    group: group.to_string(),
-                    version: version.to_string(),
-                    api_version: format!("{}/{}", group, version),
-                    kind: String::new(),
-                    plural: plural.to_string(),
-                };
+                query: &ListQuery,
+            ) -> Result<ResourcePage<Value>> {
+                let ar = dynamic_api_resource(group, version, plural);
                 let api: Api<DynamicObject> = match scope {
                     "Namespaced" => match namespace {
                         Some(ns) => Api::namespaced_with(self.client.clone(), ns, &ar),
@@ -189,13 +431,8 @@ macro_rules! define_k8s_impl {
                     },
                     _ => Api::all_with(self.client.clone(), &ar),
                 };
-                let items = api.list(&ListParams::default()).await?;
-                let values: Vec<Value> = items
-                    .items
-                    .into_iter()
-                    .map(|item| serde_json::to_value(item))
-                    .collect::<std::result::Result<Vec<_>, _>>()?;
-                Ok(values)
+                let list = api.list(&query.to_list_params()).await?;
+                Ok(ResourcePage::from(list).map_items(serialize_resources))
             }
 
             async fn get_custom_resource(
@@ -207,13 +444,7 @@ macro_rules! define_k8s_impl {
                 name: &str,
                 namespace: Option<&str>,
             ) -> Result<Value> {
-                let ar = ApiResource {
-                    group: group.to_string(),
-                    version: version.to_string(),
-                    api_version: format!("{}/{}", group, version),
-                    kind: String::new(),
-                    plural: plural.to_string(),
-                };
+                let ar = dynamic_api_resource(group, version, plural);
                 let api: Api<DynamicObject> = match scope {
                     "Namespaced" => match namespace {
                         Some(ns) => Api::namespaced_with(self.client.clone(), ns, &ar),
@@ -225,12 +456,85 @@ macro_rules! define_k8s_impl {
                 Ok(serde_json::to_value(item)?)
             }
 
+            async fn list_table(
+                &self,
+                kind: &str,
+                namespace: Option<&str>,
+                query: &ListQuery,
+            ) -> Result<ResourcePage<Value>> {
+                let (ar, namespaced) = resolve_dynamic_kind(self, kind, "list").await?;
+                let url = <DynamicObject as kube::Resource>::url_path(&ar, namespace.filter(|_| namespaced));
+                let mut request = kube::core::Request::new(url)
+                    .list(&query.to_list_params())
+                    .map_err(kube::Error::BuildRequest)?;
+                request.headers_mut().insert(
+                    http::header::ACCEPT,
+                    http::HeaderValue::from_static(TABLE_ACCEPT),
+                );
+                let table: Value = self.client.request(request).await?;
+                let metadata = table.get("metadata");
+                Ok(ResourcePage {
+                    items: table
+                        .get("rows")
+                        .and_then(Value::as_array)
+                        .cloned()
+                        .unwrap_or_default(),
+                    continue_token: metadata
+                        .and_then(|m| m.get("continue"))
+                        .and_then(Value::as_str)
+                        .filter(|c| !c.is_empty())
+                        .map(str::to_string),
+                    remaining_item_count: metadata
+                        .and_then(|m| m.get("remainingItemCount"))
+                        .and_then(Value::as_i64),
+                    column_definitions: Some(
+                        table
+                            .get("columnDefinitions")
+                            .and_then(Value::as_array)
+                            .cloned()
+                            .unwrap_or_default(),
+                    ),
+                })
+            }
+
+            async fn list_metadata(
+                &self,
+                kind: &str,
+                namespace: Option<&str>,
+                query: &ListQuery,
+            ) -> Result<ResourcePage<ObjectMeta>> {
+                let (ar, namespaced) = resolve_dynamic_kind(self, kind, "list").await?;
+                let api: Api<DynamicObject> = match namespace.filter(|_| namespaced) {
+                    Some(ns) => Api::namespaced_with(self.client.clone(), ns, &ar),
+                    None => Api::all_with(self.client.clone(), &ar),
+                };
+                let list = api.list_metadata(&query.to_list_params()).await?;
+                Ok(ResourcePage::from(list)
+                    .map_items(|items| items.into_iter().map(|item| item.metadata).collect()))
+            }
+
             async fn apiserver_version(&self) -> Result<k8s_openapi::apimachinery::pkg::version::Info> {
                 Ok(self.client.apiserver_version().await?)
             }
 
-            async fn delete_resource(&self, kind: &str, name: &str, namespace: Option<&str>) -> Result<()> {
-                let dp = DeleteParams::default();
+            async fn api_resources(&self) -> Result<Arc<Vec<ApiResourceInfo>>> {
+                self.discovery
+                    .get_or_try_init(|| async {
+                        Ok(Arc::new(discover_api_resources(&self.client).await?))
+                    })
+                    .await
+                    .cloned()
+            }
+
+            async fn delete_resource(
+                &self,
+                kind: &str,
+                name: &str,
+                namespace: Option<&str>,
+                options: &DeleteOptions,
+            ) -> Result<()> {
+                let dp = options.to_delete_params()?;
+                // Typed fast path for built-in kinds
                 match kind {
                     "Pod" => {
                         let ns = namespace.ok_or_else(|| require_namespace("Pod"))?;
@@ -351,66 +655,234 @@ macro_rules! define_k8s_impl {
                         let api: Api<ResourceQuota> = Api::namespaced(self.client.clone(), ns);
                         api.delete(name, &dp).await?;
                     }
-                    cr_kind if cr_kind.starts_with("cr:") => {
-                        let parts: Vec<&str> = cr_kind[3..].splitn(4, '/').collect();
-                        if parts.len() == 4 {
-                            let ar = ApiResource {
-                                group: parts[0].to_string(),
-                                version: parts[1].to_string(),
-                                api_version: format!("{}/{}", parts[0], parts[1]),
-                                kind: String::new(),
-                                plural: parts[2].to_string(),
-                            };
-                            let api: Api<DynamicObject> = match parts[3] {
-                                "Namespaced" => match namespace {
-                                    Some(ns) => Api::namespaced_with(self.client.clone(), ns, &ar),
-                                    None => return Err(require_namespace("CustomResource")),
-                                },
-                                _ => Api::all_with(self.client.clone(), &ar),
-                            };
-                            api.delete(name, &dp).await?;
+                    // Kinds without a typed handler above, including `cr:` kinds
+                    _ => {
+                        let (ar, namespaced) = resolve_dynamic_kind(self, kind, "delete").await?;
+                        let api = dynamic_api(self.client.clone(), &ar, namespaced, namespace)?;
+                        api.delete(name, &dp).await?;
+                    }
+                }
+                Ok(())
+            }
+
+            async fn evict_pod(&self, name: &str, namespace: &str, options: &DeleteOptions) -> Result<()> {
+                let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+                let ep = EvictParams {
+                    delete_options: Some(options.to_delete_params()?),
+                    post_options: PostParams {
+                        dry_run: options.dry_run,
+                        ..Default::default()
+                    },
+                };
+                match api.evict(name, &ep).await {
+                    Ok(_) => Ok(()),
+                    Err(kube::Error::Api(e)) if e.code == 429 => {
+                        let blocking = blocking_disruption_budgets(self.client.clone(), name, namespace)
+                            .await
+                            .unwrap_or_default();
+                        Err(K8sError::EvictionRefused(if blocking.is_empty() {
+                            e.message
                         } else {
-                            return Err(K8sError::Kube(kube::Error::Api(
-                                kube::error::ErrorResponse {
-                                    status: "Failure".to_string(),
-                                    message: format!("Invalid custom resource kind format: {}", cr_kind),
-                                    reason: "BadRequest".to_string(),
-                                    code: 400,
-                                },
-                            )));
+                            format!("{} ({})", e.message, blocking.join("; "))
+                        }))
+                    }
+                    Err(e) => Err(e.into()),
+                }
+            }
+
+            async fn rollout_restart(&self, kind: &str, name: &str, namespace: &str) -> Result<()> {
+                let patch = Patch::Merge(restart_patch());
+                let pp = PatchParams::apply("swimmer");
+                match kind {
+                    "Deployment" => {
+                        let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+                        api.patch(name, &pp, &patch).await?;
+                    }
+                    "StatefulSet" => {
+                        let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), namespace);
+                        api.patch(name, &pp, &patch).await?;
+                    }
+                    "DaemonSet" => {
+                        let api: Api<DaemonSet> = Api::namespaced(self.client.clone(), namespace);
+                        api.patch(name, &pp, &patch).await?;
+                    }
+                    _ => return Err(unsupported_rollout_kind(kind)),
+                }
+                Ok(())
+            }
+
+            async fn set_rollout_paused(&self, name: &str, namespace: &str, paused: bool) -> Result<()> {
+                let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+                let patch = serde_json::json!({
+                    "spec": {
+                        "paused": paused
+                    }
+                });
+                api.patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+                    .await?;
+                Ok(())
+            }
+
+            async fn set_rollout_partition(
+                &self,
+                name: &str,
+                namespace: &str,
+                partition: i32,
+            ) -> Result<()> {
+                let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), namespace);
+                let patch = serde_json::json!({
+                    "spec": {
+                        "updateStrategy": {
+                            "type": "RollingUpdate",
+                            "rollingUpdate": {
+                                "partition": partition
+                            }
                         }
                     }
+                });
+                api.patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+                    .await?;
+                Ok(())
+            }
+
+            async fn set_image(
+                &self,
+                kind: &str,
+                name: &str,
+                namespace: &str,
+                images: &[ContainerImage],
+            ) -> Result<()> {
+                let patch = Patch::Strategic(image_patch(kind, images)?);
+                let pp = PatchParams::default();
+                match kind {
+                    "Deployment" => {
+                        let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+                        api.patch(name, &pp, &patch).await?;
+                    }
+                    "StatefulSet" => {
+                        let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), namespace);
+                        api.patch(name, &pp, &patch).await?;
+                    }
+                    "DaemonSet" => {
+                        let api: Api<DaemonSet> = Api::namespaced(self.client.clone(), namespace);
+                        api.patch(name, &pp, &patch).await?;
+                    }
+                    "CronJob" => {
+                        let api: Api<CronJob> = Api::namespaced(self.client.clone(), namespace);
+                        api.patch(name, &pp, &patch).await?;
+                    }
+                    "Job" => {
+                        let api: Api<Job> = Api::namespaced(self.client.clone(), namespace);
+                        api.patch(name, &pp, &patch).await?;
+                    }
                     _ => {
-                        return Err(K8sError::Kube(kube::Error::Api(
-                            kube::error::ErrorResponse {
-                                status: "Failure".to_string(),
-                                message: format!("Unsupported resource kind for delete: {}", kind),
-                                reason: "BadRequest".to_string(),
-                                code: 400,
-                            },
+                        return Err(bad_request(format!(
+                            "Unsupported resource kind for set image: {}",
+                            kind
                         )));
                     }
                 }
                 Ok(())
             }
 
-            async fn rollout_restart_deployment(&self, name: &str, namespace: &str) -> Result<()> {
-                let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
-                let now = chrono::Utc::now().to_rfc3339();
+            async fn trigger_cronjob(&self, name: &str, namespace: &str) -> Result<String> {
+                let cronjob = self.get_cronjob(name, namespace).await?;
+                let api: Api<Job> = Api::namespaced(self.client.clone(), namespace);
+                let job = api.create(&PostParams::default(), &manual_job(&cronjob)).await?;
+                Ok(job.metadata.name.unwrap_or_default())
+            }
+
+            async fn set_cronjob_suspended(&self, name: &str, namespace: &str, suspended: bool) -> Result<()> {
+                let api: Api<CronJob> = Api::namespaced(self.client.clone(), namespace);
                 let patch = serde_json::json!({
                     "spec": {
-                        "template": {
-                            "metadata": {
-                                "annotations": {
-                                    "kubectl.kubernetes.io/restartedAt": now
+                        "suspend": suspended
+                    }
+                });
+                api.patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+                    .await?;
+                Ok(())
+            }
+
+            async fn set_node_unschedulable(&self, name: &str, unschedulable: bool) -> Result<()> {
+                let api: Api<Node> = Api::all(self.client.clone());
+                api.patch(name, &PatchParams::default(), &Patch::Merge(cordon_patch(unschedulable)))
+                    .await?;
+                Ok(())
+            }
+
+            async fn scale(
+                &self,
+                kind: &str,
+                name: &str,
+                namespace: Option<&str>,
+                replicas: i32,
+            ) -> Result<()> {
+                let patch = Patch::Merge(serde_json::json!({
+                    "spec": {
+                        "replicas": replicas
+                    }
+                }));
+                let pp = PatchParams::default();
+                match kind {
+                    "Deployment" => {
+                        let ns = namespace.ok_or_else(|| require_namespace("Deployment"))?;
+                        let api: Api<Deployment> = Api::namespaced(self.client.clone(), ns);
+                        api.patch_scale(name, &pp, &patch).await?;
+                    }
+                    "StatefulSet" => {
+                        let ns = namespace.ok_or_else(|| require_namespace("StatefulSet"))?;
+                        let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), ns);
+                        api.patch_scale(name, &pp, &patch).await?;
+                    }
+                    "ReplicaSet" => {
+                        let ns = namespace.ok_or_else(|| require_namespace("ReplicaSet"))?;
+                        let api: Api<ReplicaSet> = Api::namespaced(self.client.clone(), ns);
+                        api.patch_scale(name, &pp, &patch).await?;
+                    }
+                    cr_kind if cr_kind.starts_with("cr:") => {
+                        let (ar, namespaced) = parse_custom_resource_kind(cr_kind)?;
+                        let api = dynamic_api(self.client.clone(), &ar, namespaced, namespace)?;
+                        api.patch_scale(name, &pp, &patch).await?;
+                    }
+                    _ => {
+                        return Err(bad_request(format!(
+                            "Unsupported resource kind for scale: {}",
+                            kind
+                        )));
+                    }
+                }
+                Ok(())
+            }
+
+            async fn stream_pod_logs(
+                &self,
+                name: &str,
+                namespace: &str,
+                options: &PodLogOptions,
+            ) -> Result<LogLineStream> {
+                let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+                let reader = api.log_stream(name, &options.to_log_params()).await?;
+                // Split on raw bytes so that invalid UTF-8 in a container's
+                // output does not end the stream
+                let lines = futures::stream::unfold(Some(Box::pin(reader)), |reader| async move {
+                    let mut reader = reader?;
+                    let mut buf = Vec::new();
+                    match reader.read_until(b'\n', &mut buf).await {
+                        Ok(0) => None,
+                        Ok(_) => {
+                            if buf.ends_with(b"\n") {
+                                buf.pop();
+                                if buf.ends_with(b"\r") {
+                                    buf.pop();
                                 }
                             }
+                            Some((Ok(String::from_utf8_lossy(&buf).into_owned()), Some(reader)))
                         }
+                        Err(e) => Some((Err(K8sError::from(e)), None)),
                     }
                 });
-                api.patch(name, &PatchParams::apply("swimmer"), &Patch::Merge(&patch))
-                    .await?;
-                Ok(())
+                Ok(lines.boxed())
             }
         }
     };
@@ -452,6 +924,7 @@ define_k8s_trait!(
 
 pub struct RealK8sClient {
     client: Client,
+    discovery: DiscoveryCell,
 }
 
 impl RealK8sClient {
@@ -482,7 +955,10 @@ impl RealK8sClient {
         };
 
         let client = Client::try_from(config)?;
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            discovery: DiscoveryCell::default(),
+        })
     }
 }
 
@@ -522,11 +998,11 @@ define_k8s_impl!(
 
 pub use crate::mock_client::MockK8sClient;
 
-async fn get_or_create_raw_client(
+async fn get_or_create_real_client(
     pool: &K8sClientPool,
     context: Option<String>,
     kubeconfig_path: Option<String>,
-) -> Result<Client> {
+) -> Result<RealK8sClient> {
     let key = ClientCacheKey {
         context: context.clone(),
         kubeconfig_path: kubeconfig_path.clone(),
@@ -536,45 +1012,76 @@ async fn get_or_create_raw_client(
         let mut pool_guard = pool.lock().map_err(|e| K8sError::Lock(e.to_string()))?;
         pool_guard.retain(|_, v| v.created_at.elapsed() < CLIENT_CACHE_TTL);
         if let Some(cached) = pool_guard.get(&key) {
-            return Ok(cached.client.clone());
+            return Ok(RealK8sClient {
+                client: cached.client.clone(),
+                discovery: cached.discovery.clone(),
+            });
         }
     }
 
     let real_client = RealK8sClient::new(context, kubeconfig_path).await?;
-    let client = real_client.client.clone();
 
     {
         let mut pool_guard = pool.lock().map_err(|e| K8sError::Lock(e.to_string()))?;
         pool_guard.insert(
             key,
             CachedClient {
-                client: client.clone(),
+                client: real_client.client.clone(),
+                discovery: real_client.discovery.clone(),
                 created_at: Instant::now(),
             },
         );
     }
 
-    Ok(client)
+    Ok(real_client)
 }
 
-async fn get_or_create_client(
+pub(crate) async fn get_or_create_raw_client(
     pool: &K8sClientPool,
     context: Option<String>,
     kubeconfig_path: Option<String>,
-) -> Result<Box<dyn K8sClient>> {
-    let use_mock = env::var("USE_MOCK")
+) -> Result<Client> {
+    Ok(get_or_create_real_client(pool, context, kubeconfig_path)
+        .await?
+        .client)
+}
+
+pub(crate) fn use_mock() -> bool {
+    env::var("USE_MOCK")
         .unwrap_or_else(|_| "false".to_string())
         .parse::<bool>()
-        .unwrap_or(false);
+        .unwrap_or(false)
+}
 
-    if use_mock {
-        Ok(Box::new(MockK8sClient::new()))
+pub(crate) async fn get_or_create_client(
+    pool: &K8sClientPool,
+    context: Option<String>,
+    kubeconfig_path: Option<String>,
+) -> Result<Box<dyn K8sClient>> {
+    if use_mock() {
+        Ok(Box::new(MockK8sClient::with_state(
+            crate::mock_client::app_state(),
+        )))
     } else {
-        let client = get_or_create_raw_client(pool, context, kubeconfig_path).await?;
-        Ok(Box::new(RealK8sClient { client }))
+        Ok(Box::new(
+            get_or_create_real_client(pool, context, kubeconfig_path).await?,
+        ))
     }
 }
 
+/// Creates a client for the kubeconfig currently selected in the app.
+pub(crate) async fn get_or_create_client_for_app(
+    pool: &K8sClientPool,
+    kubeconfig_path: &crate::KubeconfigPath,
+    context: Option<String>,
+) -> Result<Box<dyn K8sClient>> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    get_or_create_client(pool, context, kc_path).await
+}
+
 fn serialize_resources<T: serde::Serialize>(items: Vec<T>) -> Vec<Value> {
     items
         .into_iter()
@@ -588,277 +1095,527 @@ fn serialize_resources<T: serde::Serialize>(items: Vec<T>) -> Vec<Value> {
         .collect()
 }
 
+fn serialize_page<T: serde::Serialize>(page: ResourcePage<T>) -> ResourcePage<Value> {
+    page.map_items(serialize_resources)
+}
+
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn list_resources(
     client_pool: tauri::State<'_, K8sClientPool>,
     kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    cache: tauri::State<'_, ResourceCache>,
     context: Option<String>,
     kind: String,
     namespace: Option<String>,
-) -> Result<Vec<Value>> {
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    limit: Option<u32>,
+    continue_token: Option<String>,
+    as_table: Option<bool>,
+    metadata_only: Option<bool>,
+) -> Result<ResourcePage<Value>> {
     let kc_path = kubeconfig_path
         .lock()
         .map_err(|e| K8sError::Lock(e.to_string()))?
         .clone();
+    let query = ListQuery::new(label_selector, field_selector).with_page(limit, continue_token);
+    let as_table = as_table.unwrap_or(false);
+    let metadata_only = metadata_only.unwrap_or(false);
+    // A synced store from a running watch is already up to date
+    if !as_table && !metadata_only && query.limit.is_none() && query.continue_token.is_none() {
+        let key = CacheKey::new(
+            context.clone(),
+            kc_path.clone(),
+            &kind,
+            namespace.as_deref(),
+            &query,
+        );
+        if let Some(items) = cache.snapshot(&key)? {
+            return Ok(ResourcePage {
+                items,
+                ..Default::default()
+            });
+        }
+    }
     let client = get_or_create_client(&client_pool, context, kc_path).await?;
+    if as_table {
+        return client.list_table(&kind, namespace.as_deref(), &query).await;
+    }
+    if metadata_only {
+        // Items keep the `{ metadata }` shape so callers can read names and labels as usual
+        let page = client
+            .list_metadata(&kind, namespace.as_deref(), &query)
+            .await?;
+        return Ok(page.map_items(|items| {
+            items
+                .into_iter()
+                .map(|metadata| serde_json::json!({ "metadata": metadata }))
+                .collect()
+        }));
+    }
+    fetch_resources(client.as_ref(), &kind, namespace.as_deref(), &query).await
+}
 
-    let resources: Vec<Value> = match kind.as_str() {
-        "Pods" => serialize_resources(client.list_pods(namespace.as_deref()).await?),
-        "Deployments" => serialize_resources(client.list_deployments(namespace.as_deref()).await?),
-        "Services" => serialize_resources(client.list_services(namespace.as_deref()).await?),
-        "Nodes" => serialize_resources(client.list_nodes().await?),
-        "Namespaces" => serialize_resources(client.list_namespaces().await?),
-        "ReplicaSets" => serialize_resources(client.list_replicasets(namespace.as_deref()).await?),
-        "StatefulSets" => {
-            serialize_resources(client.list_statefulsets(namespace.as_deref()).await?)
-        }
-        "DaemonSets" => serialize_resources(client.list_daemonsets(namespace.as_deref()).await?),
-        "Jobs" => serialize_resources(client.list_jobs(namespace.as_deref()).await?),
-        "CronJobs" => serialize_resources(client.list_cronjobs(namespace.as_deref()).await?),
-        "ConfigMaps" => serialize_resources(client.list_configmaps(namespace.as_deref()).await?),
-        "Secrets" => serialize_resources(client.list_secrets(namespace.as_deref()).await?),
-        "Ingresses" => serialize_resources(client.list_ingresses(namespace.as_deref()).await?),
-        "NetworkPolicies" => {
-            serialize_resources(client.list_networkpolicies(namespace.as_deref()).await?)
-        }
-        "PersistentVolumes" => serialize_resources(client.list_persistentvolumes().await?),
-        "PersistentVolumeClaims" => serialize_resources(
-            client
-                .list_persistentvolumeclaims(namespace.as_deref())
-                .await?,
-        ),
-        "StorageClasses" => serialize_resources(client.list_storageclasses().await?),
-        "Roles" => serialize_resources(client.list_roles(namespace.as_deref()).await?),
-        "ClusterRoles" => serialize_resources(client.list_clusterroles().await?),
-        "RoleBindings" => {
-            serialize_resources(client.list_rolebindings(namespace.as_deref()).await?)
-        }
-        "ClusterRoleBindings" => serialize_resources(client.list_clusterrolebindings().await?),
-        "ServiceAccounts" => {
-            serialize_resources(client.list_serviceaccounts(namespace.as_deref()).await?)
+/// Lists resources of a kind as JSON. Shared by `list_resources` and the
+/// multi-context queries.
+pub(crate) async fn fetch_resources(
+    client: &dyn K8sClient,
+    kind: &str,
+    namespace: Option<&str>,
+    query: &ListQuery,
+) -> Result<ResourcePage<Value>> {
+    // Typed fast path for built-in kinds
+    let resources = match kind {
+        "Pods" => serialize_page(client.list_pods(namespace, query).await?),
+        "Deployments" => serialize_page(client.list_deployments(namespace, query).await?),
+        "Services" => serialize_page(client.list_services(namespace, query).await?),
+        "Nodes" => serialize_page(client.list_nodes(query).await?),
+        "Namespaces" => serialize_page(client.list_namespaces(query).await?),
+        "ReplicaSets" => serialize_page(client.list_replicasets(namespace, query).await?),
+        "StatefulSets" => serialize_page(client.list_statefulsets(namespace, query).await?),
+        "DaemonSets" => serialize_page(client.list_daemonsets(namespace, query).await?),
+        "Jobs" => serialize_page(client.list_jobs(namespace, query).await?),
+        "CronJobs" => serialize_page(client.list_cronjobs(namespace, query).await?),
+        "ConfigMaps" => serialize_page(client.list_configmaps(namespace, query).await?),
+        "Secrets" => serialize_page(client.list_secrets(namespace, query).await?),
+        "Ingresses" => serialize_page(client.list_ingresses(namespace, query).await?),
+        "NetworkPolicies" => serialize_page(client.list_networkpolicies(namespace, query).await?),
+        "PersistentVolumes" => serialize_page(client.list_persistentvolumes(query).await?),
+        "PersistentVolumeClaims" => {
+            serialize_page(client.list_persistentvolumeclaims(namespace, query).await?)
         }
-        "Endpoints" => serialize_resources(client.list_endpoints(namespace.as_deref()).await?),
-        "Events" => serialize_resources(client.list_events(namespace.as_deref()).await?),
-        "HorizontalPodAutoscalers" => serialize_resources(
+        "StorageClasses" => serialize_page(client.list_storageclasses(query).await?),
+        "Roles" => serialize_page(client.list_roles(namespace, query).await?),
+        "ClusterRoles" => serialize_page(client.list_clusterroles(query).await?),
+        "RoleBindings" => serialize_page(client.list_rolebindings(namespace, query).await?),
+        "ClusterRoleBindings" => serialize_page(client.list_clusterrolebindings(query).await?),
+        "ServiceAccounts" => serialize_page(client.list_serviceaccounts(namespace, query).await?),
+        "Endpoints" => serialize_page(client.list_endpoints(namespace, query).await?),
+        "Events" => serialize_page(client.list_events(namespace, query).await?),
+        "HorizontalPodAutoscalers" => serialize_page(
             client
-                .list_horizontalpodautoscalers(namespace.as_deref())
+                .list_horizontalpodautoscalers(namespace, query)
                 .await?,
         ),
-        "LimitRanges" => serialize_resources(client.list_limitranges(namespace.as_deref()).await?),
-        "ResourceQuotas" => {
-            serialize_resources(client.list_resourcequotas(namespace.as_deref()).await?)
-        }
-        "CRDs" => serialize_resources(client.list_crds().await?),
-        cr_kind if cr_kind.starts_with("cr:") => {
-            let parts: Vec<&str> = cr_kind[3..].splitn(4, '/').collect();
-            if parts.len() == 4 {
-                client
-                    .list_custom_resources(
-                        parts[0],
-                        parts[1],
-                        parts[2],
-                        parts[3],
-                        namespace.as_deref(),
-                    )
-                    .await?
-            } else {
-                return Err(K8sError::Kube(kube::Error::Api(
-                    kube::error::ErrorResponse {
-                        status: "Failure".to_string(),
-                        message: format!("Invalid custom resource kind format: {}", cr_kind),
-                        reason: "BadRequest".to_string(),
-                        code: 400,
-                    },
-                )));
-            }
+        "LimitRanges" => serialize_page(client.list_limitranges(namespace, query).await?),
+        "ResourceQuotas" => serialize_page(client.list_resourcequotas(namespace, query).await?),
+        "CRDs" => serialize_page(client.list_crds(query).await?),
+        // Kinds without a typed handler above, including `cr:` kinds
+        other => {
+            let (ar, namespaced) = resolve_dynamic_kind(client, other, "list").await?;
+            client
+                .list_custom_resources(
+                    &ar.group,
+                    &ar.version,
+                    &ar.plural,
+                    scope_name(namespaced),
+                    namespace,
+                    query,
+                )
+                .await?
         }
-        _ => vec![],
     };
 
     Ok(resources)
 }
 
-fn require_namespace(kind: &str) -> K8sError {
-    K8sError::Kube(kube::Error::Api(kube::error::ErrorResponse {
-        status: "Failure".to_string(),
-        message: format!("Namespace required for {}", kind),
-        reason: "BadRequest".to_string(),
-        code: 400,
-    }))
+pub(crate) fn require_namespace(kind: &str) -> K8sError {
+    bad_request(format!("Namespace required for {}", kind))
 }
 
-#[tauri::command]
-pub async fn get_resource_detail(
-    client_pool: tauri::State<'_, K8sClientPool>,
-    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
-    context: Option<String>,
-    kind: String,
-    name: String,
-    namespace: Option<String>,
-) -> Result<Value> {
-    let kc_path = kubeconfig_path
-        .lock()
-        .map_err(|e| K8sError::Lock(e.to_string()))?
-        .clone();
-    let client = get_or_create_client(&client_pool, context, kc_path).await?;
-    let namespace_for_events = namespace.clone();
+/// Describes the PodDisruptionBudgets covering a pod that currently allow no
+/// disruptions, e.g. "PodDisruptionBudget web-pdb allows 0 disruptions (2/2 pods healthy)".
+async fn blocking_disruption_budgets(
+    client: Client,
+    name: &str,
+    namespace: &str,
+) -> Result<Vec<String>> {
+    use kube::core::SelectorExt;
+    let pod: Pod = Api::namespaced(client.clone(), namespace).get(name).await?;
+    let labels = pod.metadata.labels.unwrap_or_default();
+    let pdbs: Api<PodDisruptionBudget> = Api::namespaced(client, namespace);
+    let mut blocking = Vec::new();
+    for pdb in pdbs.list(&ListParams::default()).await?.items {
+        let selects_pod = pdb
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.selector.clone())
+            .and_then(|selector| kube::core::Selector::try_from(selector).ok())
+            .is_some_and(|selector| !selector.selects_all() && selector.matches(&labels));
+        let status = pdb.status.unwrap_or_default();
+        if selects_pod && status.disruptions_allowed < 1 {
+            blocking.push(format!(
+                "PodDisruptionBudget {} allows {} disruptions ({}/{} pods healthy)",
+                pdb.metadata.name.unwrap_or_default(),
+                status.disruptions_allowed,
+                status.current_healthy,
+                status.desired_healthy
+            ));
+        }
+    }
+    Ok(blocking)
+}
 
-    let resource: Value = match kind.as_str() {
-        "Pod" => {
-            let ns = namespace.ok_or_else(|| require_namespace("Pod"))?;
-            let pod = client.get_pod(&name, &ns).await?;
-            serde_json::to_value(pod)?
+/// Merge patch that makes a workload controller replace its pods, as
+/// `kubectl rollout restart` does.
+fn restart_patch() -> Value {
+    serde_json::json!({
+        "spec": {
+            "template": {
+                "metadata": {
+                    "annotations": {
+                        "kubectl.kubernetes.io/restartedAt": chrono::Utc::now().to_rfc3339()
+                    }
+                }
+            }
         }
-        "Deployment" => {
-            let ns = namespace.ok_or_else(|| require_namespace("Deployment"))?;
-            let item = client.get_deployment(&name, &ns).await?;
+    })
+}
+
+/// Strategic merge patch setting container images in a workload's pod
+/// template, as `kubectl set image` does. Containers are merged by name.
+fn image_patch(kind: &str, images: &[ContainerImage]) -> Result<Value> {
+    let entries = |init: bool| -> Vec<Value> {
+        images
+            .iter()
+            .filter(|c| c.init == init)
+            .map(|c| serde_json::json!({ "name": c.name, "image": c.image }))
+            .collect()
+    };
+    let mut pod_spec = serde_json::Map::new();
+    for (field, containers) in [
+        ("containers", entries(false)),
+        ("initContainers", entries(true)),
+    ] {
+        if !containers.is_empty() {
+            pod_spec.insert(field.to_string(), Value::Array(containers));
+        }
+    }
+    let template = serde_json::json!({ "template": { "spec": pod_spec } });
+    match kind {
+        "Deployment" | "StatefulSet" | "DaemonSet" | "Job" => {
+            Ok(serde_json::json!({ "spec": template }))
+        }
+        "CronJob" => Ok(serde_json::json!({ "spec": { "jobTemplate": { "spec": template } } })),
+        _ => Err(bad_request(format!(
+            "Unsupported resource kind for set image: {}",
+            kind
+        ))),
+    }
+}
+
+pub(crate) fn unsupported_rollout_kind(kind: &str) -> K8sError {
+    bad_request(format!("Unsupported resource kind for rollout: {}", kind))
+}
+
+/// Annotation marking a Job that was created from a CronJob by hand
+pub(crate) const MANUAL_INSTANTIATE_ANNOTATION: &str = "cronjob.kubernetes.io/instantiate";
+
+/// Builds a Job from a CronJob's jobTemplate the way `kubectl create job
+/// --from=cronjob/<name>` does. The API server appends a random suffix to the
+/// `<cronjob>-manual-` prefix so every run gets a unique name.
+fn manual_job(cronjob: &CronJob) -> Job {
+    let name = cronjob.metadata.name.clone().unwrap_or_default();
+    let template = cronjob
+        .spec
+        .as_ref()
+        .map(|spec| spec.job_template.clone())
+        .unwrap_or_default();
+    let template_metadata = template.metadata.unwrap_or_default();
+    let mut annotations = template_metadata.annotations.unwrap_or_default();
+    annotations.insert(
+        MANUAL_INSTANTIATE_ANNOTATION.to_string(),
+        "manual".to_string(),
+    );
+    Job {
+        metadata: ObjectMeta {
+            generate_name: Some(format!("{}-manual-", name)),
+            namespace: cronjob.metadata.namespace.clone(),
+            labels: template_metadata.labels,
+            annotations: Some(annotations),
+            owner_references: Some(vec![OwnerReference {
+                api_version: "batch/v1".to_string(),
+                kind: "CronJob".to_string(),
+                name,
+                uid: cronjob.metadata.uid.clone().unwrap_or_default(),
+                controller: Some(true),
+                block_owner_deletion: Some(true),
+            }]),
+            ..Default::default()
+        },
+        spec: template.spec,
+        ..Default::default()
+    }
+}
+
+/// Merge patch that marks a node (un)schedulable, as `kubectl cordon` does.
+fn cordon_patch(unschedulable: bool) -> Value {
+    serde_json::json!({
+        "spec": {
+            "unschedulable": unschedulable
+        }
+    })
+}
+
+/// Parses a `cr:group/version/plural/scope` kind string into its `ApiResource`
+/// and whether the resource is namespaced.
+fn parse_custom_resource_kind(cr_kind: &str) -> Result<(kube::api::ApiResource, bool)> {
+    let parts: Vec<&str> = cr_kind
+        .strip_prefix("cr:")
+        .unwrap_or(cr_kind)
+        .splitn(4, '/')
+        .collect();
+    if parts.len() != 4 {
+        return Err(bad_request(format!(
+            "Invalid custom resource kind format: {}",
+            cr_kind
+        )));
+    }
+    Ok((
+        dynamic_api_resource(parts[0], parts[1], parts[2]),
+        parts[3] == "Namespaced",
+    ))
+}
+
+/// Resolves a kind to its `ApiResource` and whether it is namespaced, for the
+/// paths that go through dynamic requests. `cr:group/version/plural/scope`
+/// strings are parsed directly; anything else is looked up through API
+/// discovery and must support `verb`.
+pub(crate) async fn resolve_dynamic_kind(
+    client: &dyn K8sClient,
+    kind: &str,
+    verb: &str,
+) -> Result<(kube::api::ApiResource, bool)> {
+    if kind.starts_with("cr:") {
+        return parse_custom_resource_kind(kind);
+    }
+    // Sidebar kinds are plurals ("Pods"), which discovery matches directly
+    let lookup = if kind == "CRDs" {
+        "customresourcedefinitions"
+    } else {
+        kind
+    };
+    match resolve_kind(client, lookup).await? {
+        Some(info) if info.supports(verb) => Ok((info.api_resource(), info.is_namespaced())),
+        Some(info) => Err(bad_request(format!(
+            "Resource kind {} does not support {}",
+            info.kind, verb
+        ))),
+        None => Err(unknown_kind(kind)),
+    }
+}
+
+/// The scope string the custom resource methods of `K8sClient` expect.
+fn scope_name(namespaced: bool) -> &'static str {
+    if namespaced {
+        "Namespaced"
+    } else {
+        "Cluster"
+    }
+}
+
+/// Builds an `Api<DynamicObject>` for a dynamically resolved resource.
+fn dynamic_api(
+    client: Client,
+    ar: &kube::api::ApiResource,
+    namespaced: bool,
+    namespace: Option<&str>,
+) -> Result<Api<DynamicObject>> {
+    if namespaced {
+        let ns = namespace.ok_or_else(|| require_namespace(&ar.plural))?;
+        Ok(Api::namespaced_with(client, ns, ar))
+    } else {
+        Ok(Api::all_with(client, ar))
+    }
+}
+
+pub(crate) fn unknown_kind(kind: &str) -> K8sError {
+    bad_request(format!("Unknown resource kind: {}", kind))
+}
+
+pub(crate) fn bad_request(message: String) -> K8sError {
+    K8sError::Kube(kube::Error::Api(kube::error::ErrorResponse {
+        status: "Failure".to_string(),
+        message,
+        reason: "BadRequest".to_string(),
+        code: 400,
+    }))
+}
+
+/// Fetches a single resource as JSON. Shared by `get_resource_detail` and the
+/// cross-context diff.
+pub(crate) async fn fetch_resource_detail(
+    client: &dyn K8sClient,
+    kind: &str,
+    name: &str,
+    namespace: Option<&str>,
+) -> Result<Value> {
+    // Typed fast path for built-in kinds
+    let resource: Value = match kind {
+        "Pod" => {
+            let ns = namespace.ok_or_else(|| require_namespace("Pod"))?;
+            let pod = client.get_pod(name, ns).await?;
+            serde_json::to_value(pod)?
+        }
+        "Deployment" => {
+            let ns = namespace.ok_or_else(|| require_namespace("Deployment"))?;
+            let item = client.get_deployment(name, ns).await?;
             serde_json::to_value(item)?
         }
         "Service" => {
             let ns = namespace.ok_or_else(|| require_namespace("Service"))?;
-            let item = client.get_service(&name, &ns).await?;
+            let item = client.get_service(name, ns).await?;
             serde_json::to_value(item)?
         }
         "ReplicaSet" => {
             let ns = namespace.ok_or_else(|| require_namespace("ReplicaSet"))?;
-            let item = client.get_replicaset(&name, &ns).await?;
+            let item = client.get_replicaset(name, ns).await?;
             serde_json::to_value(item)?
         }
         "StatefulSet" => {
             let ns = namespace.ok_or_else(|| require_namespace("StatefulSet"))?;
-            let item = client.get_statefulset(&name, &ns).await?;
+            let item = client.get_statefulset(name, ns).await?;
             serde_json::to_value(item)?
         }
         "DaemonSet" => {
             let ns = namespace.ok_or_else(|| require_namespace("DaemonSet"))?;
-            let item = client.get_daemonset(&name, &ns).await?;
+            let item = client.get_daemonset(name, ns).await?;
             serde_json::to_value(item)?
         }
         "Job" => {
             let ns = namespace.ok_or_else(|| require_namespace("Job"))?;
-            let item = client.get_job(&name, &ns).await?;
+            let item = client.get_job(name, ns).await?;
             serde_json::to_value(item)?
         }
         "CronJob" => {
             let ns = namespace.ok_or_else(|| require_namespace("CronJob"))?;
-            let item = client.get_cronjob(&name, &ns).await?;
+            let item = client.get_cronjob(name, ns).await?;
             serde_json::to_value(item)?
         }
         "ConfigMap" => {
             let ns = namespace.ok_or_else(|| require_namespace("ConfigMap"))?;
-            let item = client.get_configmap(&name, &ns).await?;
+            let item = client.get_configmap(name, ns).await?;
             serde_json::to_value(item)?
         }
         "Secret" => {
             let ns = namespace.ok_or_else(|| require_namespace("Secret"))?;
-            let item = client.get_secret(&name, &ns).await?;
+            let item = client.get_secret(name, ns).await?;
             serde_json::to_value(item)?
         }
         "Ingress" => {
             let ns = namespace.ok_or_else(|| require_namespace("Ingress"))?;
-            let item = client.get_ingress(&name, &ns).await?;
+            let item = client.get_ingress(name, ns).await?;
             serde_json::to_value(item)?
         }
         "NetworkPolicy" => {
             let ns = namespace.ok_or_else(|| require_namespace("NetworkPolicy"))?;
-            let item = client.get_networkpolicy(&name, &ns).await?;
+            let item = client.get_networkpolicy(name, ns).await?;
             serde_json::to_value(item)?
         }
         "PersistentVolumeClaim" => {
             let ns = namespace.ok_or_else(|| require_namespace("PersistentVolumeClaim"))?;
-            let item = client.get_persistentvolumeclaim(&name, &ns).await?;
+            let item = client.get_persistentvolumeclaim(name, ns).await?;
             serde_json::to_value(item)?
         }
         "Role" => {
             let ns = namespace.ok_or_else(|| require_namespace("Role"))?;
-            let item = client.get_role(&name, &ns).await?;
+            let item = client.get_role(name, ns).await?;
             serde_json::to_value(item)?
         }
         "RoleBinding" => {
             let ns = namespace.ok_or_else(|| require_namespace("RoleBinding"))?;
-            let item = client.get_rolebinding(&name, &ns).await?;
+            let item = client.get_rolebinding(name, ns).await?;
             serde_json::to_value(item)?
         }
         "ServiceAccount" => {
             let ns = namespace.ok_or_else(|| require_namespace("ServiceAccount"))?;
-            let item = client.get_serviceaccount(&name, &ns).await?;
+            let item = client.get_serviceaccount(name, ns).await?;
             serde_json::to_value(item)?
         }
         "Node" => {
-            let item = client.get_node(&name).await?;
+            let item = client.get_node(name).await?;
             serde_json::to_value(item)?
         }
         "Namespace" => {
-            let item = client.get_namespace(&name).await?;
+            let item = client.get_namespace(name).await?;
             serde_json::to_value(item)?
         }
         "PersistentVolume" => {
-            let item = client.get_persistentvolume(&name).await?;
+            let item = client.get_persistentvolume(name).await?;
             serde_json::to_value(item)?
         }
         "StorageClass" => {
-            let item = client.get_storageclass(&name).await?;
+            let item = client.get_storageclass(name).await?;
             serde_json::to_value(item)?
         }
         "ClusterRole" => {
-            let item = client.get_clusterrole(&name).await?;
+            let item = client.get_clusterrole(name).await?;
             serde_json::to_value(item)?
         }
         "ClusterRoleBinding" => {
-            let item = client.get_clusterrolebinding(&name).await?;
+            let item = client.get_clusterrolebinding(name).await?;
             serde_json::to_value(item)?
         }
         "Endpoints" => {
             let ns = namespace.ok_or_else(|| require_namespace("Endpoints"))?;
-            let item = client.get_endpoints(&name, &ns).await?;
+            let item = client.get_endpoints(name, ns).await?;
             serde_json::to_value(item)?
         }
         "Event" => {
             let ns = namespace.ok_or_else(|| require_namespace("Event"))?;
-            let item = client.get_event(&name, &ns).await?;
+            let item = client.get_event(name, ns).await?;
             serde_json::to_value(item)?
         }
         "HorizontalPodAutoscaler" => {
             let ns = namespace.ok_or_else(|| require_namespace("HorizontalPodAutoscaler"))?;
-            let item = client.get_horizontalpodautoscaler(&name, &ns).await?;
+            let item = client.get_horizontalpodautoscaler(name, ns).await?;
             serde_json::to_value(item)?
         }
         "LimitRange" => {
             let ns = namespace.ok_or_else(|| require_namespace("LimitRange"))?;
-            let item = client.get_limitrange(&name, &ns).await?;
+            let item = client.get_limitrange(name, ns).await?;
             serde_json::to_value(item)?
         }
         "ResourceQuota" => {
             let ns = namespace.ok_or_else(|| require_namespace("ResourceQuota"))?;
-            let item = client.get_resourcequota(&name, &ns).await?;
+            let item = client.get_resourcequota(name, ns).await?;
             serde_json::to_value(item)?
         }
-        cr_kind if cr_kind.starts_with("cr:") => {
-            let parts: Vec<&str> = cr_kind[3..].splitn(4, '/').collect();
-            if parts.len() == 4 {
-                client
-                    .get_custom_resource(
-                        parts[0],
-                        parts[1],
-                        parts[2],
-                        parts[3],
-                        &name,
-                        namespace.as_deref(),
-                    )
-                    .await?
-            } else {
-                return Err(K8sError::Kube(kube::Error::Api(
-                    kube::error::ErrorResponse {
-                        status: "Failure".to_string(),
-                        message: format!("Invalid custom resource kind format: {}", cr_kind),
-                        reason: "BadRequest".to_string(),
-                        code: 400,
-                    },
-                )));
-            }
+        // Kinds without a typed handler above, including `cr:` kinds
+        other => {
+            let (ar, namespaced) = resolve_dynamic_kind(client, other, "get").await?;
+            client
+                .get_custom_resource(
+                    &ar.group,
+                    &ar.version,
+                    &ar.plural,
+                    scope_name(namespaced),
+                    name,
+                    namespace,
+                )
+                .await?
         }
-        _ => serde_json::json!({}),
     };
+    Ok(resource)
+}
+
+#[tauri::command]
+pub async fn get_resource_detail(
+    client_pool: tauri::State<'_, K8sClientPool>,
+    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    kind: String,
+    name: String,
+    namespace: Option<String>,
+) -> Result<Value> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_client(&client_pool, context, kc_path).await?;
+    let resource =
+        fetch_resource_detail(client.as_ref(), &kind, &name, namespace.as_deref()).await?;
 
     let event_supported_kinds = [
         "Pod",
@@ -876,8 +1633,14 @@ pub async fn get_resource_detail(
     ];
 
     let events: Vec<Value> = if event_supported_kinds.contains(&kind.as_str()) {
-        let ns = namespace_for_events.as_deref();
-        let all_events = client.list_events(ns).await?;
+        let ns = namespace.as_deref();
+        let all_events = client
+            .list_events(
+                ns,
+                &ListQuery::new(None, Some(format!("involvedObject.name={}", name))),
+            )
+            .await?
+            .items;
         let filtered_events: Vec<Event> = all_events
             .into_iter()
             .filter(|event| {
@@ -985,6 +1748,17 @@ pub async fn get_cluster_overview_info(
     })
 }
 
+/// Counts resources across all namespaces from their metadata alone, which
+/// keeps the payload small on large clusters.
+async fn count_resources(
+    client: &dyn K8sClient,
+    kind: &str,
+    field_selector: Option<&str>,
+) -> Result<usize> {
+    let query = ListQuery::new(None, field_selector.map(str::to_string));
+    Ok(client.list_metadata(kind, None, &query).await?.items.len())
+}
+
 #[tauri::command]
 pub async fn get_cluster_stats(
     client_pool: tauri::State<'_, K8sClientPool>,
@@ -997,7 +1771,7 @@ pub async fn get_cluster_stats(
         .clone();
     let client = get_or_create_client(&client_pool, Some(context_id), kc_path).await?;
 
-    let nodes = client.list_nodes().await?;
+    let nodes = client.list_nodes(&ListQuery::default()).await?.items;
     let total_nodes = nodes.len();
     let ready_nodes = nodes
         .iter()
@@ -1014,27 +1788,12 @@ pub async fn get_cluster_stats(
         })
         .count();
 
-    let pods = client.list_pods(None).await?;
-    let total_pods = pods.len();
-    let running_pods = pods
-        .iter()
-        .filter(|pod| {
-            pod.status
-                .as_ref()
-                .and_then(|status| status.phase.as_deref())
-                .unwrap_or("")
-                == "Running"
-        })
-        .count();
-
-    let namespaces = client.list_namespaces().await?;
-    let namespace_count = namespaces.len();
-
-    let deployments = client.list_deployments(None).await?;
-    let deployment_count = deployments.len();
-
-    let jobs = client.list_jobs(None).await?;
-    let job_count = jobs.len();
+    let client = client.as_ref();
+    let total_pods = count_resources(client, "Pods", None).await?;
+    let running_pods = count_resources(client, "Pods", Some("status.phase=Running")).await?;
+    let namespace_count = count_resources(client, "Namespaces", None).await?;
+    let deployment_count = count_resources(client, "Deployments", None).await?;
+    let job_count = count_resources(client, "Jobs", None).await?;
 
     Ok(ClusterStats {
         total_nodes,
@@ -1058,7 +1817,7 @@ pub async fn list_crd_groups(
         .map_err(|e| K8sError::Lock(e.to_string()))?
         .clone();
     let client = get_or_create_client(&client_pool, context, kc_path).await?;
-    let crds = client.list_crds().await?;
+    let crds = client.list_crds(&ListQuery::default()).await?.items;
 
     let mut groups: HashMap<String, Vec<CrdResourceInfo>> = HashMap::new();
 
@@ -1099,15 +1858,21 @@ pub async fn list_crd_groups(
     Ok(result)
 }
 
-pub type WatcherHandle = Arc<Mutex<HashMap<String, tokio::task::JoinHandle<()>>>>;
-
 #[derive(Clone, Serialize)]
 struct ResourceWatchEvent {
     event_type: String,
     resource: Value,
 }
 
-fn run_watcher<T>(app: AppHandle, api: Api<T>, watch_id: String) -> tokio::task::JoinHandle<()>
+/// Starts a reflector for `api` and forwards its events to every subscribed
+/// watch id. The store behind it serves `list_resources` once synced.
+fn run_watcher<T>(
+    app: AppHandle,
+    api: Api<T>,
+    writer: reflector::store::Writer<T>,
+    config: watcher::Config,
+    watch_id: String,
+) -> SharedWatch
 where
     T: kube::Resource
         + serde::de::DeserializeOwned
@@ -1115,12 +1880,18 @@ where
         + Clone
         + std::fmt::Debug
         + Send
+        + Sync
         + 'static,
-    <T as kube::Resource>::DynamicType: Default,
+    <T as kube::Resource>::DynamicType: Eq + std::hash::Hash + Clone + Send + Sync,
 {
-    let stream = watcher(api, watcher::Config::default());
-
-    tokio::spawn(async move {
+    let store = writer.as_reader();
+    let stream = reflector(writer, watcher(api, config).default_backoff());
+    let subscribers: Subscribers = Arc::new(Mutex::new([watch_id.clone()].into()));
+    let ready = Arc::new(AtomicBool::new(false));
+
+    let task_subscribers = subscribers.clone();
+    let task_ready = ready.clone();
+    let task = tokio::spawn(async move {
         log::info!("Watch stream task started for watch_id: {}", watch_id);
         futures::pin_mut!(stream);
         while let Some(result) = stream.next().await {
@@ -1132,33 +1903,54 @@ where
                         Event::Apply(obj) => ("modified", obj),
                         Event::Delete(obj) => ("deleted", obj),
                         Event::InitApply(obj) => ("modified", obj),
-                        Event::Init | Event::InitDone => continue,
+                        Event::InitDone => {
+                            task_ready.store(true, Ordering::Release);
+                            continue;
+                        }
+                        // A relist is starting; the store is stale until it completes
+                        Event::Init => {
+                            task_ready.store(false, Ordering::Release);
+                            continue;
+                        }
                     };
 
                     if let Ok(value) = serde_json::to_value(&resource) {
-                        let _ = app.emit(
-                            &format!("resource-watch-{}", watch_id),
-                            ResourceWatchEvent {
-                                event_type: event_type.to_string(),
-                                resource: value,
-                            },
-                        );
+                        let ids: Vec<String> = match task_subscribers.lock() {
+                            Ok(ids) => ids.iter().cloned().collect(),
+                            Err(_) => continue,
+                        };
+                        let payload = ResourceWatchEvent {
+                            event_type: event_type.to_string(),
+                            resource: value,
+                        };
+                        for id in ids {
+                            let _ = app.emit(&format!("resource-watch-{}", id), payload.clone());
+                        }
                     }
                 }
                 Err(e) => {
                     log::warn!("Watch error (will retry): {}", e);
+                    task_ready.store(false, Ordering::Release);
                 }
             }
         }
-    })
+    });
+
+    SharedWatch {
+        store: Box::new(store),
+        ready,
+        subscribers,
+        task,
+    }
 }
 
 fn watch_namespaced_or_all<T>(
     app: AppHandle,
     client: Client,
     namespace: Option<String>,
+    query: &ListQuery,
     watch_id: String,
-) -> tokio::task::JoinHandle<()>
+) -> SharedWatch
 where
     T: kube::Resource<Scope = kube::core::NamespaceResourceScope>
         + serde::de::DeserializeOwned
@@ -1166,22 +1958,30 @@ where
         + Clone
         + std::fmt::Debug
         + Send
+        + Sync
         + 'static,
-    <T as kube::Resource>::DynamicType: Default,
+    <T as kube::Resource>::DynamicType: Default + Eq + std::hash::Hash + Clone + Send + Sync,
 {
     let api: Api<T> = if let Some(ns) = namespace.as_deref() {
         Api::namespaced(client, ns)
     } else {
         Api::all(client)
     };
-    run_watcher(app, api, watch_id)
+    run_watcher(
+        app,
+        api,
+        reflector::store::Writer::default(),
+        query.to_watcher_config(),
+        watch_id,
+    )
 }
 
 fn watch_cluster_scoped<T>(
     app: AppHandle,
     client: Client,
+    query: &ListQuery,
     watch_id: String,
-) -> tokio::task::JoinHandle<()>
+) -> SharedWatch
 where
     T: kube::Resource<Scope = kube::core::ClusterResourceScope>
         + serde::de::DeserializeOwned
@@ -1189,22 +1989,54 @@ where
         + Clone
         + std::fmt::Debug
         + Send
+        + Sync
         + 'static,
-    <T as kube::Resource>::DynamicType: Default,
+    <T as kube::Resource>::DynamicType: Default + Eq + std::hash::Hash + Clone + Send + Sync,
 {
     let api: Api<T> = Api::all(client);
-    run_watcher(app, api, watch_id)
+    run_watcher(
+        app,
+        api,
+        reflector::store::Writer::default(),
+        query.to_watcher_config(),
+        watch_id,
+    )
+}
+
+fn watch_dynamic(
+    app: AppHandle,
+    client: Client,
+    ar: &kube::api::ApiResource,
+    namespaced: bool,
+    namespace: Option<String>,
+    query: &ListQuery,
+    watch_id: String,
+) -> SharedWatch {
+    let api: Api<DynamicObject> = match namespace.as_deref() {
+        Some(ns) if namespaced => Api::namespaced_with(client, ns, ar),
+        _ => Api::all_with(client, ar),
+    };
+    run_watcher(
+        app,
+        api,
+        reflector::store::Writer::new(ar.clone()),
+        query.to_watcher_config(),
+        watch_id,
+    )
 }
 
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn start_watch_resources(
     app: AppHandle,
-    watcher_handle: tauri::State<'_, WatcherHandle>,
+    cache: tauri::State<'_, ResourceCache>,
     client_pool: tauri::State<'_, K8sClientPool>,
     kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
     context: Option<String>,
     kind: String,
     namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
 ) -> Result<String> {
     log::info!(
         "start_watch_resources called: kind={}, namespace={:?}, context={:?}",
@@ -1218,7 +2050,26 @@ pub async fn start_watch_resources(
         .map_err(|e| K8sError::Lock(e.to_string()))?
         .clone();
 
-    let client = get_or_create_raw_client(&client_pool, context, kc_path).await?;
+    let query = ListQuery::new(label_selector, field_selector);
+    let key = CacheKey::new(
+        context.clone(),
+        kc_path.clone(),
+        &kind,
+        namespace.as_deref(),
+        &query,
+    );
+    if cache.attach(&key, &watch_id)? {
+        log::info!(
+            "Joined shared watch for kind: {}, namespace: {:?}, watch_id: {}",
+            kind,
+            namespace,
+            watch_id
+        );
+        return Ok(watch_id);
+    }
+
+    let real_client = get_or_create_real_client(&client_pool, context, kc_path).await?;
+    let client = real_client.client.clone();
 
     log::info!(
         "Starting watch for kind: {}, namespace: {:?}, watch_id: {}",
@@ -1227,56 +2078,131 @@ pub async fn start_watch_resources(
         watch_id
     );
 
-    let handle = match kind.as_str() {
-        "Pods" => watch_namespaced_or_all::<Pod>(app, client, namespace, watch_id.clone()),
+    // Typed fast path for built-in kinds
+    let shared = match kind.as_str() {
+        "Pods" => watch_namespaced_or_all::<Pod>(app, client, namespace, &query, watch_id.clone()),
         "Deployments" => {
-            watch_namespaced_or_all::<Deployment>(app, client, namespace, watch_id.clone())
+            watch_namespaced_or_all::<Deployment>(app, client, namespace, &query, watch_id.clone())
+        }
+        "Services" => {
+            watch_namespaced_or_all::<Service>(app, client, namespace, &query, watch_id.clone())
         }
-        "Services" => watch_namespaced_or_all::<Service>(app, client, namespace, watch_id.clone()),
-        "Nodes" => watch_cluster_scoped::<Node>(app, client, watch_id.clone()),
-        "Namespaces" => watch_cluster_scoped::<Namespace>(app, client, watch_id.clone()),
+        "Nodes" => watch_cluster_scoped::<Node>(app, client, &query, watch_id.clone()),
+        "Namespaces" => watch_cluster_scoped::<Namespace>(app, client, &query, watch_id.clone()),
         "ReplicaSets" => {
-            watch_namespaced_or_all::<ReplicaSet>(app, client, namespace, watch_id.clone())
+            watch_namespaced_or_all::<ReplicaSet>(app, client, namespace, &query, watch_id.clone())
         }
         "StatefulSets" => {
-            watch_namespaced_or_all::<StatefulSet>(app, client, namespace, watch_id.clone())
+            watch_namespaced_or_all::<StatefulSet>(app, client, namespace, &query, watch_id.clone())
         }
         "DaemonSets" => {
-            watch_namespaced_or_all::<DaemonSet>(app, client, namespace, watch_id.clone())
+            watch_namespaced_or_all::<DaemonSet>(app, client, namespace, &query, watch_id.clone())
+        }
+        "Jobs" => watch_namespaced_or_all::<Job>(app, client, namespace, &query, watch_id.clone()),
+        "CronJobs" => {
+            watch_namespaced_or_all::<CronJob>(app, client, namespace, &query, watch_id.clone())
         }
-        "Jobs" => watch_namespaced_or_all::<Job>(app, client, namespace, watch_id.clone()),
-        "CronJobs" => watch_namespaced_or_all::<CronJob>(app, client, namespace, watch_id.clone()),
         "ConfigMaps" => {
-            watch_namespaced_or_all::<ConfigMap>(app, client, namespace, watch_id.clone())
+            watch_namespaced_or_all::<ConfigMap>(app, client, namespace, &query, watch_id.clone())
         }
-        "Secrets" => watch_namespaced_or_all::<Secret>(app, client, namespace, watch_id.clone()),
-        "Ingresses" => watch_namespaced_or_all::<Ingress>(app, client, namespace, watch_id.clone()),
-        _ => {
-            log::warn!("Unsupported resource kind for watch: {}", kind);
-            return Ok(watch_id);
+        "Secrets" => {
+            watch_namespaced_or_all::<Secret>(app, client, namespace, &query, watch_id.clone())
+        }
+        "Ingresses" => {
+            watch_namespaced_or_all::<Ingress>(app, client, namespace, &query, watch_id.clone())
+        }
+        "NetworkPolicies" => watch_namespaced_or_all::<NetworkPolicy>(
+            app,
+            client,
+            namespace,
+            &query,
+            watch_id.clone(),
+        ),
+        "PersistentVolumes" => {
+            watch_cluster_scoped::<PersistentVolume>(app, client, &query, watch_id.clone())
+        }
+        "PersistentVolumeClaims" => watch_namespaced_or_all::<PersistentVolumeClaim>(
+            app,
+            client,
+            namespace,
+            &query,
+            watch_id.clone(),
+        ),
+        "StorageClasses" => {
+            watch_cluster_scoped::<StorageClass>(app, client, &query, watch_id.clone())
+        }
+        "Roles" => {
+            watch_namespaced_or_all::<Role>(app, client, namespace, &query, watch_id.clone())
+        }
+        "ClusterRoles" => {
+            watch_cluster_scoped::<ClusterRole>(app, client, &query, watch_id.clone())
+        }
+        "RoleBindings" => {
+            watch_namespaced_or_all::<RoleBinding>(app, client, namespace, &query, watch_id.clone())
+        }
+        "ClusterRoleBindings" => {
+            watch_cluster_scoped::<ClusterRoleBinding>(app, client, &query, watch_id.clone())
+        }
+        "ServiceAccounts" => watch_namespaced_or_all::<ServiceAccount>(
+            app,
+            client,
+            namespace,
+            &query,
+            watch_id.clone(),
+        ),
+        "Endpoints" => {
+            watch_namespaced_or_all::<Endpoints>(app, client, namespace, &query, watch_id.clone())
+        }
+        "Events" => {
+            watch_namespaced_or_all::<Event>(app, client, namespace, &query, watch_id.clone())
+        }
+        "HorizontalPodAutoscalers" => watch_namespaced_or_all::<HorizontalPodAutoscaler>(
+            app,
+            client,
+            namespace,
+            &query,
+            watch_id.clone(),
+        ),
+        "LimitRanges" => {
+            watch_namespaced_or_all::<LimitRange>(app, client, namespace, &query, watch_id.clone())
+        }
+        "ResourceQuotas" => watch_namespaced_or_all::<ResourceQuota>(
+            app,
+            client,
+            namespace,
+            &query,
+            watch_id.clone(),
+        ),
+        "CRDs" => {
+            watch_cluster_scoped::<CustomResourceDefinition>(app, client, &query, watch_id.clone())
+        }
+        // Kinds without a typed handler above, including `cr:` kinds
+        other => {
+            let (ar, namespaced) = resolve_dynamic_kind(&real_client, other, "watch").await?;
+            watch_dynamic(
+                app,
+                client,
+                &ar,
+                namespaced,
+                namespace,
+                &query,
+                watch_id.clone(),
+            )
         }
     };
 
-    watcher_handle
-        .lock()
-        .map_err(|e| K8sError::Lock(e.to_string()))?
-        .insert(watch_id.clone(), handle);
+    cache.insert(key, &watch_id, shared)?;
     Ok(watch_id)
 }
 
+/// Unsubscribes a watch. The shared reflector behind it stops when its last
+/// subscriber leaves.
 #[tauri::command]
 pub async fn stop_watch_resources(
-    watcher_handle: tauri::State<'_, WatcherHandle>,
+    cache: tauri::State<'_, ResourceCache>,
     watch_id: String,
 ) -> Result<()> {
-    if let Some(handle) = watcher_handle
-        .lock()
-        .map_err(|e| K8sError::Lock(e.to_string()))?
-        .remove(&watch_id)
-    {
-        handle.abort();
-    }
-    Ok(())
+    cache.detach(&watch_id)
 }
 
 #[tauri::command]
@@ -1287,6 +2213,7 @@ pub async fn delete_resource(
     kind: String,
     name: String,
     namespace: Option<String>,
+    options: Option<DeleteOptions>,
 ) -> Result<()> {
     let kc_path = kubeconfig_path
         .lock()
@@ -1294,22 +2221,463 @@ pub async fn delete_resource(
         .clone();
     let client = get_or_create_client(&client_pool, context, kc_path).await?;
     client
-        .delete_resource(&kind, &name, namespace.as_deref())
+        .delete_resource(
+            &kind,
+            &name,
+            namespace.as_deref(),
+            &options.unwrap_or_default(),
+        )
         .await
 }
 
 #[tauri::command]
-pub async fn rollout_restart_deployment(
+pub async fn evict_pod(
     client_pool: tauri::State<'_, K8sClientPool>,
     kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
     context: Option<String>,
     name: String,
     namespace: String,
+    options: Option<DeleteOptions>,
+) -> Result<()> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_client(&client_pool, context, kc_path).await?;
+    client
+        .evict_pod(&name, &namespace, &options.unwrap_or_default())
+        .await
+}
+
+#[tauri::command]
+pub async fn scale_resource(
+    client_pool: tauri::State<'_, K8sClientPool>,
+    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    kind: String,
+    name: String,
+    namespace: Option<String>,
+    replicas: i32,
+) -> Result<()> {
+    if replicas < 0 {
+        return Err(bad_request(format!(
+            "Replicas must not be negative: {}",
+            replicas
+        )));
+    }
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_client(&client_pool, context, kc_path).await?;
+    client
+        .scale(&kind, &name, namespace.as_deref(), replicas)
+        .await
+}
+
+#[tauri::command]
+pub async fn cordon_node(
+    client_pool: tauri::State<'_, K8sClientPool>,
+    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
 ) -> Result<()> {
     let kc_path = kubeconfig_path
         .lock()
         .map_err(|e| K8sError::Lock(e.to_string()))?
         .clone();
     let client = get_or_create_client(&client_pool, context, kc_path).await?;
-    client.rollout_restart_deployment(&name, &namespace).await
+    client.set_node_unschedulable(&name, true).await
+}
+
+#[tauri::command]
+pub async fn uncordon_node(
+    client_pool: tauri::State<'_, K8sClientPool>,
+    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
+) -> Result<()> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_client(&client_pool, context, kc_path).await?;
+    client.set_node_unschedulable(&name, false).await
+}
+
+pub type LogStreamHandle = Arc<Mutex<HashMap<String, tokio::task::JoinHandle<()>>>>;
+
+#[derive(Clone, Serialize)]
+struct PodLogEvent {
+    event_type: String,
+    data: String,
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn stream_pod_logs(
+    app: AppHandle,
+    log_stream_handle: tauri::State<'_, LogStreamHandle>,
+    client_pool: tauri::State<'_, K8sClientPool>,
+    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
+    namespace: String,
+    options: PodLogOptions,
+) -> Result<String> {
+    let stream_id = uuid::Uuid::new_v4().to_string();
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_client(&client_pool, context, kc_path).await?;
+    let mut stream = client.stream_pod_logs(&name, &namespace, &options).await?;
+
+    log::info!(
+        "Starting log stream for pod {}/{}, container: {:?}, stream_id: {}",
+        namespace,
+        name,
+        options.container,
+        stream_id
+    );
+
+    let event_name = format!("pod-logs-{}", stream_id);
+    let streams = log_stream_handle.inner().clone();
+    let id = stream_id.clone();
+    // Held while spawning so a stream that ends at once cannot remove itself
+    // before it is registered
+    let mut registry = log_stream_handle
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?;
+    let handle = tokio::spawn(async move {
+        let finished = |streams: &LogStreamHandle| {
+            if let Ok(mut streams) = streams.lock() {
+                streams.remove(&id);
+            }
+        };
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(line) => {
+                    let _ = app.emit(
+                        &event_name,
+                        PodLogEvent {
+                            event_type: "line".to_string(),
+                            data: line,
+                        },
+                    );
+                }
+                Err(e) => {
+                    log::warn!("Log stream error for {}: {}", event_name, e);
+                    let _ = app.emit(
+                        &event_name,
+                        PodLogEvent {
+                            event_type: "error".to_string(),
+                            data: e.to_string(),
+                        },
+                    );
+                    finished(&streams);
+                    return;
+                }
+            }
+        }
+        let _ = app.emit(
+            &event_name,
+            PodLogEvent {
+                event_type: "end".to_string(),
+                data: String::new(),
+            },
+        );
+        finished(&streams);
+    });
+
+    registry.insert(stream_id.clone(), handle);
+    Ok(stream_id)
+}
+
+#[tauri::command]
+pub async fn stop_pod_logs(
+    log_stream_handle: tauri::State<'_, LogStreamHandle>,
+    stream_id: String,
+) -> Result<()> {
+    if let Some(handle) = log_stream_handle
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .remove(&stream_id)
+    {
+        handle.abort();
+    }
+    Ok(())
+}
+
+#[derive(Clone, Serialize)]
+struct WorkloadLogEvent {
+    event_type: String,
+    pod: String,
+    container: String,
+    data: String,
+}
+
+/// Resolves the pod label selector of a workload. `kind` may also be
+/// "Selector", in which case `name` is used as the label selector itself.
+async fn resolve_workload_selector(
+    client: &dyn K8sClient,
+    kind: &str,
+    name: &str,
+    namespace: &str,
+) -> Result<String> {
+    let selector = match kind {
+        "Deployment" => client
+            .get_deployment(name, namespace)
+            .await?
+            .spec
+            .map(|spec| spec.selector),
+        "StatefulSet" => client
+            .get_statefulset(name, namespace)
+            .await?
+            .spec
+            .map(|spec| spec.selector),
+        "DaemonSet" => client
+            .get_daemonset(name, namespace)
+            .await?
+            .spec
+            .map(|spec| spec.selector),
+        "ReplicaSet" => client
+            .get_replicaset(name, namespace)
+            .await?
+            .spec
+            .map(|spec| spec.selector),
+        "Job" => client
+            .get_job(name, namespace)
+            .await?
+            .spec
+            .and_then(|spec| spec.selector),
+        "Selector" => return Ok(name.to_string()),
+        _ => {
+            return Err(bad_request(format!(
+                "Unsupported resource kind for workload logs: {}",
+                kind
+            )))
+        }
+    };
+
+    let selector =
+        selector.ok_or_else(|| bad_request(format!("{} {} has no pod selector", kind, name)))?;
+    let selector =
+        kube::core::Selector::try_from(selector).map_err(|e| bad_request(e.to_string()))?;
+    if selector.selects_all() {
+        return Err(bad_request(format!(
+            "{} {} selects every pod in the namespace",
+            kind, name
+        )));
+    }
+    Ok(selector.to_string())
+}
+
+/// Aborts every per-container log follower when the owning stream task is
+/// stopped or finishes.
+struct LogFollowers(HashMap<String, (String, tokio::task::JoinHandle<()>)>);
+
+impl Drop for LogFollowers {
+    fn drop(&mut self) {
+        for (_, handle) in self.0.values() {
+            handle.abort();
+        }
+    }
+}
+
+fn follow_container_logs(
+    app: AppHandle,
+    client: Arc<dyn K8sClient>,
+    event_name: String,
+    namespace: String,
+    pod: String,
+    options: PodLogOptions,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let container = options.container.clone().unwrap_or_default();
+        let emit = |event_type: &str, data: String| {
+            let _ = app.emit(
+                &event_name,
+                WorkloadLogEvent {
+                    event_type: event_type.to_string(),
+                    pod: pod.clone(),
+                    container: container.clone(),
+                    data,
+                },
+            );
+        };
+
+        let mut stream = match client.stream_pod_logs(&pod, &namespace, &options).await {
+            Ok(stream) => stream,
+            Err(e) => {
+                emit("error", e.to_string());
+                return;
+            }
+        };
+        emit("attached", String::new());
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(line) => emit("line", line),
+                Err(e) => {
+                    emit("error", e.to_string());
+                    return;
+                }
+            }
+        }
+        emit("detached", String::new());
+    })
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn stream_workload_logs(
+    app: AppHandle,
+    log_stream_handle: tauri::State<'_, LogStreamHandle>,
+    client_pool: tauri::State<'_, K8sClientPool>,
+    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    kind: String,
+    name: String,
+    namespace: String,
+    options: PodLogOptions,
+) -> Result<String> {
+    let stream_id = uuid::Uuid::new_v4().to_string();
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client: Arc<dyn K8sClient> =
+        Arc::from(get_or_create_client(&client_pool, context.clone(), kc_path.clone()).await?);
+    let selector = resolve_workload_selector(client.as_ref(), &kind, &name, &namespace).await?;
+
+    log::info!(
+        "Starting workload log stream for {} {}/{} (selector: {}), stream_id: {}",
+        kind,
+        namespace,
+        name,
+        selector,
+        stream_id
+    );
+
+    let stream: BoxStream<'static, watcher::Result<watcher::Event<Pod>>> = if use_mock() {
+        // The mock client cannot watch, so replay the matching pods once as an initial sync.
+        let pods = client
+            .list_pods(
+                Some(&namespace),
+                &ListQuery::new(Some(selector.clone()), None),
+            )
+            .await?
+            .items;
+        futures::stream::iter(
+            std::iter::once(watcher::Event::Init)
+                .chain(pods.into_iter().map(watcher::Event::InitApply))
+                .chain(std::iter::once(watcher::Event::InitDone))
+                .map(Ok),
+        )
+        .chain(futures::stream::pending())
+        .boxed()
+    } else {
+        let raw_client = get_or_create_raw_client(&client_pool, context, kc_path).await?;
+        let api: Api<Pod> = Api::namespaced(raw_client, &namespace);
+        watcher(api, watcher::Config::default().labels(&selector))
+            .default_backoff()
+            .boxed()
+    };
+    let event_name = format!("workload-logs-{}", stream_id);
+
+    let handle = tokio::spawn(async move {
+        use kube::runtime::watcher::Event;
+
+        let mut stream = stream;
+        // Keyed by container ID so that a restarted container gets a new follower.
+        let mut followers = LogFollowers(HashMap::new());
+        let mut initial_sync = true;
+        while let Some(result) = stream.next().await {
+            let (pod, is_initial) = match result {
+                Ok(Event::Init) => {
+                    initial_sync = true;
+                    continue;
+                }
+                Ok(Event::InitDone) => {
+                    initial_sync = false;
+                    continue;
+                }
+                Ok(Event::InitApply(pod)) => (pod, initial_sync),
+                Ok(Event::Apply(pod)) => (pod, false),
+                Ok(Event::Delete(pod)) => {
+                    let pod_name = pod.metadata.name.unwrap_or_default();
+                    followers.0.retain(|_, (owner, handle)| {
+                        if *owner == pod_name {
+                            handle.abort();
+                            false
+                        } else {
+                            true
+                        }
+                    });
+                    continue;
+                }
+                Err(e) => {
+                    log::warn!("Workload log watch error (will retry): {}", e);
+                    continue;
+                }
+            };
+
+            let pod_name = pod.metadata.name.clone().unwrap_or_default();
+            let statuses = pod
+                .status
+                .map(|status| {
+                    let mut statuses = status.init_container_statuses.unwrap_or_default();
+                    statuses.extend(status.container_statuses.unwrap_or_default());
+                    statuses
+                })
+                .unwrap_or_default();
+            for status in statuses {
+                if options
+                    .container
+                    .as_deref()
+                    .is_some_and(|container| container != status.name)
+                {
+                    continue;
+                }
+                let started = status
+                    .state
+                    .as_ref()
+                    .is_some_and(|state| state.running.is_some() || state.terminated.is_some());
+                let Some(container_id) = status.container_id.filter(|_| started) else {
+                    continue;
+                };
+                if followers.0.contains_key(&container_id) {
+                    continue;
+                }
+
+                let mut container_options = PodLogOptions {
+                    container: Some(status.name.clone()),
+                    follow: true,
+                    ..options.clone()
+                };
+                // Containers that appear after the initial sync are new, so show their whole log.
+                if !is_initial {
+                    container_options.tail_lines = None;
+                    container_options.since_seconds = None;
+                }
+                let follower = follow_container_logs(
+                    app.clone(),
+                    client.clone(),
+                    event_name.clone(),
+                    namespace.clone(),
+                    pod_name.clone(),
+                    container_options,
+                );
+                followers
+                    .0
+                    .insert(container_id, (pod_name.clone(), follower));
+            }
+        }
+    });
+
+    log_stream_handle
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .insert(stream_id.clone(), handle);
+    Ok(stream_id)
 }
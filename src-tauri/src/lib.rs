@@ -1,5 +1,14 @@
+mod api_discovery;
+mod apply;
+mod bulk;
+mod cronjob;
+mod drain;
 mod k8s_api;
 mod mock_client;
+mod multi_cluster;
+mod port_forward;
+mod resource_cache;
+mod rollout;
 mod terminal;
 
 use kube::config::Kubeconfig;
@@ -8,6 +17,9 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use thiserror::Error;
 
+use drain::NodeDrains;
+use port_forward::PortForwards;
+use rollout::RolloutStatusWatches;
 use terminal::TerminalSessions;
 
 /// Inherit PATH and KUBECONFIG from the user's shell on macOS/Linux app launch
@@ -54,6 +66,8 @@ pub enum Error {
     Terminal(String),
     #[error("Lock error: {0}")]
     Lock(String),
+    #[error(transparent)]
+    K8s(#[from] k8s_api::K8sError),
 }
 
 impl serde::Serialize for Error {
@@ -142,9 +156,13 @@ pub fn run() {
     fix_shell_env();
 
     let terminal_sessions: TerminalSessions = Arc::new(Mutex::new(HashMap::new()));
-    let watcher_handle: k8s_api::WatcherHandle = Arc::new(Mutex::new(HashMap::new()));
+    let resource_cache = resource_cache::ResourceCache::default();
+    let log_stream_handle: k8s_api::LogStreamHandle = Arc::new(Mutex::new(HashMap::new()));
     let kubeconfig_path: KubeconfigPath = Arc::new(Mutex::new(None));
     let client_pool: k8s_api::K8sClientPool = Arc::new(Mutex::new(HashMap::new()));
+    let port_forwards: PortForwards = Arc::new(Mutex::new(HashMap::new()));
+    let node_drains: NodeDrains = Arc::new(Mutex::new(HashMap::new()));
+    let rollout_status_watches: RolloutStatusWatches = Arc::new(Mutex::new(HashMap::new()));
 
     tauri::Builder::default()
         .plugin(
@@ -161,25 +179,64 @@ pub fn run() {
         )
         .plugin(tauri_plugin_opener::init())
         .manage(terminal_sessions)
-        .manage(watcher_handle)
+        .manage(resource_cache)
+        .manage(log_stream_handle)
         .manage(kubeconfig_path)
         .manage(client_pool)
+        .manage(port_forwards)
+        .manage(node_drains)
+        .manage(rollout_status_watches)
         .invoke_handler(tauri::generate_handler![
             get_kube_contexts,
             set_kubeconfig_path,
             get_kubeconfig_path,
             terminal::create_terminal_session,
+            terminal::create_exec_session,
             terminal::write_to_terminal,
+            terminal::resize_terminal,
             terminal::close_terminal_session,
             k8s_api::list_resources,
             k8s_api::get_resource_detail,
+            multi_cluster::list_resources_multi,
+            multi_cluster::diff_resource_across_contexts,
             k8s_api::get_cluster_overview_info,
             k8s_api::get_cluster_stats,
             k8s_api::list_crd_groups,
+            api_discovery::list_api_resources,
             k8s_api::start_watch_resources,
             k8s_api::stop_watch_resources,
             k8s_api::delete_resource,
-            k8s_api::rollout_restart_deployment
+            k8s_api::evict_pod,
+            k8s_api::scale_resource,
+            k8s_api::cordon_node,
+            k8s_api::uncordon_node,
+            k8s_api::stream_pod_logs,
+            k8s_api::stream_workload_logs,
+            k8s_api::stop_pod_logs,
+            bulk::bulk_delete_resources,
+            bulk::bulk_rollout_restart,
+            bulk::bulk_scale_resources,
+            apply::apply_resource_yaml,
+            apply::diff_resource,
+            port_forward::start_port_forward,
+            port_forward::list_port_forwards,
+            port_forward::stop_port_forward,
+            drain::drain_node,
+            drain::stop_node_drain,
+            rollout::rollout_restart,
+            rollout::rollout_pause,
+            rollout::rollout_resume,
+            rollout::set_rollout_partition,
+            rollout::list_workload_containers,
+            rollout::set_image,
+            rollout::get_rollout_history,
+            rollout::rollout_undo,
+            rollout::watch_rollout_status,
+            rollout::stop_rollout_status,
+            cronjob::trigger_cronjob,
+            cronjob::suspend_cronjob,
+            cronjob::resume_cronjob,
+            cronjob::get_cronjob_runs
         ])
         .setup(|app| {
             use tauri::{menu::*, Emitter};
@@ -1,20 +1,25 @@
 use async_trait::async_trait;
+use futures::StreamExt;
 use k8s_openapi::api::apps::v1::{
     DaemonSet, Deployment, DeploymentSpec, DeploymentStatus, ReplicaSet, ReplicaSetSpec,
-    ReplicaSetStatus, StatefulSet, StatefulSetSpec, StatefulSetStatus,
+    ReplicaSetStatus, RollingUpdateStatefulSetStrategy, StatefulSet, StatefulSetSpec,
+    StatefulSetStatus, StatefulSetUpdateStrategy,
 };
 use k8s_openapi::api::autoscaling::v2::{
     HorizontalPodAutoscaler, HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus,
 };
-use k8s_openapi::api::batch::v1::{CronJob, CronJobSpec, CronJobStatus, Job, JobSpec, JobStatus};
+use k8s_openapi::api::batch::v1::{
+    CronJob, CronJobSpec, CronJobStatus, Job, JobSpec, JobStatus, JobTemplateSpec,
+};
 use k8s_openapi::api::core::v1::{
-    ConfigMap, Container, ContainerStatus, EndpointAddress, EndpointPort, EndpointSubset,
-    Endpoints, Event, EventSource, LimitRange, LimitRangeItem, LimitRangeSpec, Namespace,
-    NamespaceSpec, NamespaceStatus, Node, NodeAddress, NodeCondition, NodeSpec, NodeStatus,
-    NodeSystemInfo, PersistentVolume, PersistentVolumeClaim, PersistentVolumeClaimSpec,
-    PersistentVolumeClaimStatus, PersistentVolumeSpec, PersistentVolumeStatus, Pod, PodSpec,
-    PodStatus, ResourceQuota, ResourceQuotaSpec, ResourceQuotaStatus, Secret, Service,
-    ServiceAccount, ServiceSpec, ServiceStatus, VolumeResourceRequirements,
+    ConfigMap, Container, ContainerState, ContainerStateRunning, ContainerStatus, EndpointAddress,
+    EndpointPort, EndpointSubset, Endpoints, Event, EventSource, LimitRange, LimitRangeItem,
+    LimitRangeSpec, Namespace, NamespaceSpec, NamespaceStatus, Node, NodeAddress, NodeCondition,
+    NodeSpec, NodeStatus, NodeSystemInfo, PersistentVolume, PersistentVolumeClaim,
+    PersistentVolumeClaimSpec, PersistentVolumeClaimStatus, PersistentVolumeSpec,
+    PersistentVolumeStatus, Pod, PodSpec, PodStatus, PodTemplateSpec, ResourceQuota,
+    ResourceQuotaSpec, ResourceQuotaStatus, Secret, Service, ServiceAccount, ServiceSpec,
+    ServiceStatus, VolumeResourceRequirements,
 };
 use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
 use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding};
@@ -22,17 +27,260 @@ use k8s_openapi::api::storage::v1::StorageClass;
 use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta, Time};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock};
 
 use serde_json::Value;
 
-use crate::k8s_api::{K8sClient, K8sError, Result};
+use crate::api_discovery::ApiResourceInfo;
+use crate::k8s_api::{
+    bad_request, fetch_resources, unsupported_rollout_kind, ContainerImage, DeleteOptions,
+    K8sClient, K8sError, ListQuery, LogLineStream, PodLogOptions, ResourcePage, Result,
+};
+
+/// Writes recorded by the mock client and applied to the objects it returns.
+#[derive(Default)]
+pub struct MockState {
+    /// Replica counts requested through `scale`, keyed by (kind, namespace, name)
+    pub scaled_replicas: HashMap<(String, String, String), i32>,
+    /// Nodes marked unschedulable through `set_node_unschedulable`
+    pub cordoned_nodes: HashSet<String>,
+    /// Calls to `rollout_restart` in the order they were made, as (kind, namespace, name)
+    pub rollout_restarts: Vec<(String, String, String)>,
+    /// Deployments paused through `set_rollout_paused`, as (namespace, name)
+    pub paused_deployments: HashSet<(String, String)>,
+    /// StatefulSet partitions set through `set_rollout_partition`, keyed by (namespace, name)
+    pub rollout_partitions: HashMap<(String, String), i32>,
+    /// Images set through `set_image`, keyed by (kind, namespace, name, container)
+    pub container_images: HashMap<(String, String, String, String), String>,
+    /// CronJobs run through `trigger_cronjob`, in call order, as (namespace, name)
+    pub triggered_cronjobs: Vec<(String, String)>,
+    /// CronJobs suspended through `set_cronjob_suspended`, as (namespace, name)
+    pub suspended_cronjobs: HashSet<(String, String)>,
+}
+
+/// Filters a canned list by the query's selectors the way the API server
+/// would.
+fn select<T: serde::Serialize>(items: Vec<T>, query: &ListQuery) -> Result<Vec<T>> {
+    if query.label_selector.is_none() && query.field_selector.is_none() {
+        return Ok(items);
+    }
+    let mut selected = Vec::with_capacity(items.len());
+    for item in items {
+        let value = serde_json::to_value(&item)?;
+        if matches_label_selector(&value, query.label_selector.as_deref())?
+            && matches_field_selector(&value, query.field_selector.as_deref())
+        {
+            selected.push(item);
+        }
+    }
+    Ok(selected)
+}
+
+/// Filters a canned list by the query's selectors and pages it the way the
+/// API server would. Continue tokens are plain offsets into the list.
+fn paginate<T: serde::Serialize>(items: Vec<T>, query: &ListQuery) -> Result<ResourcePage<T>> {
+    let items = select(items, query)?;
+    let offset = match query.continue_token.as_deref() {
+        Some(token) => token
+            .parse::<usize>()
+            .map_err(|_| bad_request(format!("Invalid continue token: {}", token)))?,
+        None => 0,
+    };
+    let total = items.len();
+    let limit = query.limit.map_or(total, |l| l as usize);
+    let items: Vec<T> = items.into_iter().skip(offset).take(limit).collect();
+    let next = offset + items.len();
+    Ok(ResourcePage {
+        items,
+        continue_token: (next < total).then(|| next.to_string()),
+        remaining_item_count: (next < total).then(|| (total - next) as i64),
+        column_definitions: None,
+    })
+}
 
-pub struct MockK8sClient;
+/// Applies the equality-based terms of a label selector (`key=value`,
+/// `key!=value`, `key` and `!key`) to a serialized object. Set-based terms
+/// are rejected rather than silently ignored.
+fn matches_label_selector(item: &Value, selector: Option<&str>) -> Result<bool> {
+    let labels = item.pointer("/metadata/labels");
+    let label = |key: &str| labels.and_then(|l| l.get(key)).and_then(Value::as_str);
+    for term in selector.into_iter().flat_map(|s| s.split(',')) {
+        let term = term.trim();
+        if term.is_empty() {
+            continue;
+        }
+        if term.contains('(') || term.contains(')') {
+            return Err(bad_request(format!(
+                "Set-based label selector terms are not supported by the mock client: {}",
+                term
+            )));
+        }
+        let matched = if let Some((key, value)) = term.split_once("!=") {
+            label(key.trim()) != Some(value.trim())
+        } else if let Some((key, value)) = term.split_once('=') {
+            label(key.trim()) == Some(value.trim_start_matches('=').trim())
+        } else if let Some(key) = term.strip_prefix('!') {
+            label(key.trim()).is_none()
+        } else {
+            label(term).is_some()
+        };
+        if !matched {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Applies `path=value` and `path!=value` terms of a field selector to a
+/// serialized object, e.g. `status.phase=Running`.
+fn matches_field_selector(item: &Value, selector: Option<&str>) -> bool {
+    selector.into_iter().flat_map(|s| s.split(',')).all(|term| {
+        let (path, expected, negated) = match term.split_once("!=") {
+            Some((path, value)) => (path, value, true),
+            None => match term.split_once('=') {
+                Some((path, value)) => (path, value.trim_start_matches('='), false),
+                None => return true,
+            },
+        };
+        let pointer = format!("/{}", path.trim().replace('.', "/"));
+        let actual = item.pointer(&pointer).and_then(Value::as_str).unwrap_or("");
+        (actual == expected.trim()) != negated
+    })
+}
+
+/// State shared by the mock clients created for app commands, so that changes
+/// made by one command show up in later reads.
+pub fn app_state() -> Arc<Mutex<MockState>> {
+    static STATE: OnceLock<Arc<Mutex<MockState>>> = OnceLock::new();
+    STATE.get_or_init(Default::default).clone()
+}
+
+pub struct MockK8sClient {
+    state: Arc<Mutex<MockState>>,
+}
 
 impl MockK8sClient {
+    /// Creates a client with its own, empty state.
+    #[cfg(test)]
     pub fn new() -> Self {
-        Self
+        Self::with_state(Default::default())
+    }
+
+    /// Creates a client that records into `state`.
+    pub fn with_state(state: Arc<Mutex<MockState>>) -> Self {
+        Self { state }
+    }
+
+    /// Returns the writes recorded so far.
+    #[cfg(test)]
+    pub fn state(&self) -> Result<std::sync::MutexGuard<'_, MockState>> {
+        self.state.lock().map_err(|e| K8sError::Lock(e.to_string()))
+    }
+
+    fn replicas(&self, kind: &str, namespace: &str, name: &str, default: i32) -> i32 {
+        self.state
+            .lock()
+            .ok()
+            .and_then(|state| {
+                state
+                    .scaled_replicas
+                    .get(&(kind.to_string(), namespace.to_string(), name.to_string()))
+                    .copied()
+            })
+            .unwrap_or(default)
+    }
+
+    fn deployment_paused(&self, namespace: &str, name: &str) -> Option<bool> {
+        let paused = self.state.lock().is_ok_and(|state| {
+            state
+                .paused_deployments
+                .contains(&(namespace.to_string(), name.to_string()))
+        });
+        paused.then_some(true)
+    }
+
+    fn statefulset_update_strategy(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Option<StatefulSetUpdateStrategy> {
+        let partition = self.state.lock().ok().and_then(|state| {
+            state
+                .rollout_partitions
+                .get(&(namespace.to_string(), name.to_string()))
+                .copied()
+        })?;
+        Some(StatefulSetUpdateStrategy {
+            type_: Some("RollingUpdate".to_string()),
+            rolling_update: Some(RollingUpdateStatefulSetStrategy {
+                partition: Some(partition),
+                ..Default::default()
+            }),
+        })
+    }
+
+    fn cronjob_suspended(&self, namespace: &str, name: &str) -> Option<bool> {
+        let suspended = self.state.lock().is_ok_and(|state| {
+            state
+                .suspended_cronjobs
+                .contains(&(namespace.to_string(), name.to_string()))
+        });
+        suspended.then_some(true)
+    }
+
+    fn node_spec(&self, name: &str) -> NodeSpec {
+        let cordoned = self
+            .state
+            .lock()
+            .is_ok_and(|state| state.cordoned_nodes.contains(name));
+        NodeSpec {
+            unschedulable: cordoned.then_some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Pod template with a single container. The image set through `set_image`
+    /// replaces `image` when there is one.
+    fn pod_template(
+        &self,
+        kind: &str,
+        namespace: &str,
+        name: &str,
+        labels: Option<BTreeMap<String, String>>,
+        container: &str,
+        image: &str,
+    ) -> PodTemplateSpec {
+        let image = self
+            .state
+            .lock()
+            .ok()
+            .and_then(|state| {
+                state
+                    .container_images
+                    .get(&(
+                        kind.to_string(),
+                        namespace.to_string(),
+                        name.to_string(),
+                        container.to_string(),
+                    ))
+                    .cloned()
+            })
+            .unwrap_or_else(|| image.to_string());
+        PodTemplateSpec {
+            metadata: Some(ObjectMeta {
+                labels,
+                ..Default::default()
+            }),
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: container.to_string(),
+                    image: Some(image),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+        }
     }
 
     fn create_metadata(
@@ -65,6 +313,15 @@ impl MockK8sClient {
         phase: String,
         restart_count: i32,
     ) -> Pod {
+        let state = (phase == "Running").then(|| ContainerState {
+            running: Some(ContainerStateRunning {
+                started_at: Some(creation_timestamp.clone()),
+            }),
+            ..Default::default()
+        });
+        let container_id = state
+            .as_ref()
+            .map(|_| format!("containerd://{}-{}", uid, container_name));
         Pod {
             metadata: Self::create_metadata(
                 name,
@@ -90,6 +347,8 @@ impl MockK8sClient {
                     restart_count,
                     image,
                     image_id: "docker://sha256:abc123".to_string(),
+                    container_id,
+                    state,
                     ..Default::default()
                 }]),
                 ..Default::default()
@@ -100,7 +359,11 @@ impl MockK8sClient {
 
 #[async_trait]
 impl K8sClient for MockK8sClient {
-    async fn list_pods(&self, _namespace: Option<&str>) -> Result<Vec<Pod>> {
+    async fn list_pods(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Pod>> {
         let creation_time1 = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T10:00:00Z")
                 .unwrap()
@@ -120,7 +383,7 @@ impl K8sClient for MockK8sClient {
         labels2.insert("app".to_string(), "api".to_string());
         labels2.insert("version".to_string(), "2.0".to_string());
 
-        Ok(vec![
+        let items = vec![
             Self::create_pod(
                 "web-app-1".to_string(),
                 "default".to_string(),
@@ -145,7 +408,8 @@ impl K8sClient for MockK8sClient {
                 "Running".to_string(),
                 2,
             ),
-        ])
+        ];
+        paginate(items, query)
     }
 
     async fn get_pod(&self, name: &str, namespace: &str) -> Result<Pod> {
@@ -171,7 +435,11 @@ impl K8sClient for MockK8sClient {
         ))
     }
 
-    async fn list_deployments(&self, _namespace: Option<&str>) -> Result<Vec<Deployment>> {
+    async fn list_deployments(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Deployment>> {
         let creation_time1 = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T08:00:00Z")
                 .unwrap()
@@ -195,7 +463,7 @@ impl K8sClient for MockK8sClient {
         let mut selector_labels2 = BTreeMap::new();
         selector_labels2.insert("app".to_string(), "api".to_string());
 
-        Ok(vec![
+        let items = vec![
             Deployment {
                 metadata: Self::create_metadata(
                     "web-deployment".to_string(),
@@ -205,11 +473,20 @@ impl K8sClient for MockK8sClient {
                     Some(labels1),
                 ),
                 spec: Some(DeploymentSpec {
-                    replicas: Some(3),
+                    replicas: Some(self.replicas("Deployment", "default", "web-deployment", 3)),
                     selector: LabelSelector {
-                        match_labels: Some(selector_labels1),
+                        match_labels: Some(selector_labels1.clone()),
                         ..Default::default()
                     },
+                    paused: self.deployment_paused("default", "web-deployment"),
+                    template: self.pod_template(
+                        "Deployment",
+                        "default",
+                        "web-deployment",
+                        Some(selector_labels1),
+                        "web",
+                        "nginx:1.21",
+                    ),
                     ..Default::default()
                 }),
                 status: Some(DeploymentStatus {
@@ -228,11 +505,20 @@ impl K8sClient for MockK8sClient {
                     Some(labels2),
                 ),
                 spec: Some(DeploymentSpec {
-                    replicas: Some(2),
+                    replicas: Some(self.replicas("Deployment", "default", "api-deployment", 2)),
                     selector: LabelSelector {
-                        match_labels: Some(selector_labels2),
+                        match_labels: Some(selector_labels2.clone()),
                         ..Default::default()
                     },
+                    paused: self.deployment_paused("default", "api-deployment"),
+                    template: self.pod_template(
+                        "Deployment",
+                        "default",
+                        "api-deployment",
+                        Some(selector_labels2),
+                        "api",
+                        "myapp/api:2.0",
+                    ),
                     ..Default::default()
                 }),
                 status: Some(DeploymentStatus {
@@ -242,7 +528,8 @@ impl K8sClient for MockK8sClient {
                     ..Default::default()
                 }),
             },
-        ])
+        ];
+        paginate(items, query)
     }
 
     async fn get_deployment(&self, name: &str, namespace: &str) -> Result<Deployment> {
@@ -266,11 +553,20 @@ impl K8sClient for MockK8sClient {
                 Some(labels),
             ),
             spec: Some(DeploymentSpec {
-                replicas: Some(3),
+                replicas: Some(self.replicas("Deployment", namespace, name, 3)),
                 selector: LabelSelector {
-                    match_labels: Some(selector_labels),
+                    match_labels: Some(selector_labels.clone()),
                     ..Default::default()
                 },
+                paused: self.deployment_paused(namespace, name),
+                template: self.pod_template(
+                    "Deployment",
+                    namespace,
+                    name,
+                    Some(selector_labels),
+                    "web",
+                    "nginx:1.21",
+                ),
                 ..Default::default()
             }),
             status: Some(DeploymentStatus {
@@ -282,7 +578,11 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_services(&self, _namespace: Option<&str>) -> Result<Vec<Service>> {
+    async fn list_services(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Service>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T08:00:00Z")
                 .unwrap()
@@ -294,7 +594,7 @@ impl K8sClient for MockK8sClient {
         let mut selector = BTreeMap::new();
         selector.insert("app".to_string(), "web".to_string());
 
-        Ok(vec![Service {
+        let items = vec![Service {
             metadata: Self::create_metadata(
                 "web-service".to_string(),
                 Some("default".to_string()),
@@ -318,7 +618,8 @@ impl K8sClient for MockK8sClient {
                 load_balancer: Some(Default::default()),
                 ..Default::default()
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_service(&self, name: &str, namespace: &str) -> Result<Service> {
@@ -356,7 +657,7 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_nodes(&self) -> Result<Vec<Node>> {
+    async fn list_nodes(&self, query: &ListQuery) -> Result<ResourcePage<Node>> {
         let creation_time1 = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
                 .unwrap()
@@ -384,7 +685,7 @@ impl K8sClient for MockK8sClient {
         capacity2.insert("cpu".to_string(), Quantity("8".to_string()));
         capacity2.insert("memory".to_string(), Quantity("16Gi".to_string()));
 
-        Ok(vec![
+        let items = vec![
             Node {
                 metadata: Self::create_metadata(
                     "node-1".to_string(),
@@ -393,7 +694,7 @@ impl K8sClient for MockK8sClient {
                     Some(creation_time1),
                     Some(labels1),
                 ),
-                spec: Some(NodeSpec::default()),
+                spec: Some(self.node_spec("node-1")),
                 status: Some(NodeStatus {
                     conditions: Some(vec![NodeCondition {
                         type_: "Ready".to_string(),
@@ -428,7 +729,7 @@ impl K8sClient for MockK8sClient {
                     Some(creation_time2),
                     Some(labels2),
                 ),
-                spec: Some(NodeSpec::default()),
+                spec: Some(self.node_spec("node-2")),
                 status: Some(NodeStatus {
                     conditions: Some(vec![NodeCondition {
                         type_: "Ready".to_string(),
@@ -449,7 +750,8 @@ impl K8sClient for MockK8sClient {
                     ..Default::default()
                 }),
             },
-        ])
+        ];
+        paginate(items, query)
     }
 
     async fn get_node(&self, name: &str) -> Result<Node> {
@@ -473,7 +775,7 @@ impl K8sClient for MockK8sClient {
                 Some(creation_time),
                 Some(labels),
             ),
-            spec: Some(NodeSpec::default()),
+            spec: Some(self.node_spec(name)),
             status: Some(NodeStatus {
                 conditions: Some(vec![NodeCondition {
                     type_: "Ready".to_string(),
@@ -496,7 +798,7 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_namespaces(&self) -> Result<Vec<Namespace>> {
+    async fn list_namespaces(&self, query: &ListQuery) -> Result<ResourcePage<Namespace>> {
         let creation_time1 = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
                 .unwrap()
@@ -513,7 +815,7 @@ impl K8sClient for MockK8sClient {
                 .with_timezone(&chrono::Utc),
         );
 
-        Ok(vec![
+        let items = vec![
             Namespace {
                 metadata: Self::create_metadata(
                     "default".to_string(),
@@ -556,7 +858,8 @@ impl K8sClient for MockK8sClient {
                     conditions: None,
                 }),
             },
-        ])
+        ];
+        paginate(items, query)
     }
 
     async fn get_namespace(&self, name: &str) -> Result<Namespace> {
@@ -582,7 +885,11 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_replicasets(&self, _namespace: Option<&str>) -> Result<Vec<ReplicaSet>> {
+    async fn list_replicasets(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<ReplicaSet>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T08:00:00Z")
                 .unwrap()
@@ -594,7 +901,7 @@ impl K8sClient for MockK8sClient {
         let mut selector_labels = BTreeMap::new();
         selector_labels.insert("app".to_string(), "web".to_string());
 
-        Ok(vec![ReplicaSet {
+        let items = vec![ReplicaSet {
             metadata: Self::create_metadata(
                 "web-rs".to_string(),
                 Some("default".to_string()),
@@ -603,7 +910,7 @@ impl K8sClient for MockK8sClient {
                 Some(labels),
             ),
             spec: Some(ReplicaSetSpec {
-                replicas: Some(3),
+                replicas: Some(self.replicas("ReplicaSet", "default", "web-rs", 3)),
                 selector: LabelSelector {
                     match_labels: Some(selector_labels),
                     ..Default::default()
@@ -615,7 +922,8 @@ impl K8sClient for MockK8sClient {
                 ready_replicas: Some(3),
                 ..Default::default()
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_replicaset(&self, name: &str, namespace: &str) -> Result<ReplicaSet> {
@@ -639,7 +947,7 @@ impl K8sClient for MockK8sClient {
                 Some(labels),
             ),
             spec: Some(ReplicaSetSpec {
-                replicas: Some(3),
+                replicas: Some(self.replicas("ReplicaSet", namespace, name, 3)),
                 selector: LabelSelector {
                     match_labels: Some(selector_labels),
                     ..Default::default()
@@ -654,7 +962,11 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_statefulsets(&self, _namespace: Option<&str>) -> Result<Vec<StatefulSet>> {
+    async fn list_statefulsets(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<StatefulSet>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T08:00:00Z")
                 .unwrap()
@@ -666,7 +978,7 @@ impl K8sClient for MockK8sClient {
         let mut selector_labels = BTreeMap::new();
         selector_labels.insert("app".to_string(), "db".to_string());
 
-        Ok(vec![StatefulSet {
+        let items = vec![StatefulSet {
             metadata: Self::create_metadata(
                 "db-statefulset".to_string(),
                 Some("default".to_string()),
@@ -675,12 +987,21 @@ impl K8sClient for MockK8sClient {
                 Some(labels),
             ),
             spec: Some(StatefulSetSpec {
-                replicas: Some(3),
+                replicas: Some(self.replicas("StatefulSet", "default", "db-statefulset", 3)),
                 service_name: "db-service".to_string(),
                 selector: LabelSelector {
-                    match_labels: Some(selector_labels),
+                    match_labels: Some(selector_labels.clone()),
                     ..Default::default()
                 },
+                update_strategy: self.statefulset_update_strategy("default", "db-statefulset"),
+                template: self.pod_template(
+                    "StatefulSet",
+                    "default",
+                    "db-statefulset",
+                    Some(selector_labels),
+                    "postgres",
+                    "postgres:15",
+                ),
                 ..Default::default()
             }),
             status: Some(StatefulSetStatus {
@@ -688,7 +1009,8 @@ impl K8sClient for MockK8sClient {
                 ready_replicas: Some(3),
                 ..Default::default()
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_statefulset(&self, name: &str, namespace: &str) -> Result<StatefulSet> {
@@ -712,12 +1034,21 @@ impl K8sClient for MockK8sClient {
                 Some(labels),
             ),
             spec: Some(StatefulSetSpec {
-                replicas: Some(3),
+                replicas: Some(self.replicas("StatefulSet", namespace, name, 3)),
                 service_name: "db-service".to_string(),
                 selector: LabelSelector {
-                    match_labels: Some(selector_labels),
+                    match_labels: Some(selector_labels.clone()),
                     ..Default::default()
                 },
+                update_strategy: self.statefulset_update_strategy(namespace, name),
+                template: self.pod_template(
+                    "StatefulSet",
+                    namespace,
+                    name,
+                    Some(selector_labels),
+                    "postgres",
+                    "postgres:15",
+                ),
                 ..Default::default()
             }),
             status: Some(StatefulSetStatus {
@@ -728,7 +1059,11 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_daemonsets(&self, _namespace: Option<&str>) -> Result<Vec<DaemonSet>> {
+    async fn list_daemonsets(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<DaemonSet>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
                 .unwrap()
@@ -740,7 +1075,7 @@ impl K8sClient for MockK8sClient {
         let mut selector_labels = BTreeMap::new();
         selector_labels.insert("app".to_string(), "logging".to_string());
 
-        Ok(vec![DaemonSet {
+        let items = vec![DaemonSet {
             metadata: Self::create_metadata(
                 "logging-daemonset".to_string(),
                 Some("kube-system".to_string()),
@@ -749,6 +1084,14 @@ impl K8sClient for MockK8sClient {
                 Some(labels),
             ),
             spec: Some(k8s_openapi::api::apps::v1::DaemonSetSpec {
+                template: self.pod_template(
+                    "DaemonSet",
+                    "kube-system",
+                    "logging-daemonset",
+                    Some(selector_labels.clone()),
+                    "fluentd",
+                    "fluent/fluentd:v1.16",
+                ),
                 selector: LabelSelector {
                     match_labels: Some(selector_labels),
                     ..Default::default()
@@ -761,7 +1104,8 @@ impl K8sClient for MockK8sClient {
                 desired_number_scheduled: 2,
                 ..Default::default()
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_daemonset(&self, name: &str, namespace: &str) -> Result<DaemonSet> {
@@ -785,6 +1129,14 @@ impl K8sClient for MockK8sClient {
                 Some(labels),
             ),
             spec: Some(k8s_openapi::api::apps::v1::DaemonSetSpec {
+                template: self.pod_template(
+                    "DaemonSet",
+                    namespace,
+                    name,
+                    Some(selector_labels.clone()),
+                    "fluentd",
+                    "fluent/fluentd:v1.16",
+                ),
                 selector: LabelSelector {
                     match_labels: Some(selector_labels),
                     ..Default::default()
@@ -800,14 +1152,18 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_jobs(&self, _namespace: Option<&str>) -> Result<Vec<Job>> {
+    async fn list_jobs(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Job>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T09:00:00Z")
                 .unwrap()
                 .with_timezone(&chrono::Utc),
         );
 
-        Ok(vec![Job {
+        let items = vec![Job {
             metadata: Self::create_metadata(
                 "backup-job".to_string(),
                 Some("default".to_string()),
@@ -818,6 +1174,14 @@ impl K8sClient for MockK8sClient {
             spec: Some(JobSpec {
                 completions: Some(1),
                 parallelism: Some(1),
+                template: self.pod_template(
+                    "Job",
+                    "default",
+                    "backup-job",
+                    None,
+                    "backup",
+                    "busybox:1.36",
+                ),
                 ..Default::default()
             }),
             status: Some(JobStatus {
@@ -825,7 +1189,8 @@ impl K8sClient for MockK8sClient {
                 active: Some(0),
                 ..Default::default()
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_job(&self, name: &str, namespace: &str) -> Result<Job> {
@@ -846,6 +1211,7 @@ impl K8sClient for MockK8sClient {
             spec: Some(JobSpec {
                 completions: Some(1),
                 parallelism: Some(1),
+                template: self.pod_template("Job", namespace, name, None, "backup", "busybox:1.36"),
                 ..Default::default()
             }),
             status: Some(JobStatus {
@@ -856,7 +1222,11 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_cronjobs(&self, _namespace: Option<&str>) -> Result<Vec<CronJob>> {
+    async fn list_cronjobs(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<CronJob>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
                 .unwrap()
@@ -868,7 +1238,7 @@ impl K8sClient for MockK8sClient {
                 .with_timezone(&chrono::Utc),
         );
 
-        Ok(vec![CronJob {
+        let items = vec![CronJob {
             metadata: Self::create_metadata(
                 "daily-backup".to_string(),
                 Some("default".to_string()),
@@ -878,13 +1248,29 @@ impl K8sClient for MockK8sClient {
             ),
             spec: Some(CronJobSpec {
                 schedule: "0 2 * * *".to_string(),
+                suspend: self.cronjob_suspended("default", "daily-backup"),
+                job_template: JobTemplateSpec {
+                    spec: Some(JobSpec {
+                        template: self.pod_template(
+                            "CronJob",
+                            "default",
+                            "daily-backup",
+                            None,
+                            "backup",
+                            "busybox:1.36",
+                        ),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
                 ..Default::default()
             }),
             status: Some(CronJobStatus {
                 last_schedule_time: Some(last_schedule_time),
                 ..Default::default()
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_cronjob(&self, name: &str, namespace: &str) -> Result<CronJob> {
@@ -909,6 +1295,21 @@ impl K8sClient for MockK8sClient {
             ),
             spec: Some(CronJobSpec {
                 schedule: "0 2 * * *".to_string(),
+                suspend: self.cronjob_suspended(namespace, name),
+                job_template: JobTemplateSpec {
+                    spec: Some(JobSpec {
+                        template: self.pod_template(
+                            "CronJob",
+                            namespace,
+                            name,
+                            None,
+                            "backup",
+                            "busybox:1.36",
+                        ),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
                 ..Default::default()
             }),
             status: Some(CronJobStatus {
@@ -918,7 +1319,11 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_configmaps(&self, _namespace: Option<&str>) -> Result<Vec<ConfigMap>> {
+    async fn list_configmaps(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<ConfigMap>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T08:00:00Z")
                 .unwrap()
@@ -927,7 +1332,7 @@ impl K8sClient for MockK8sClient {
         let mut data = BTreeMap::new();
         data.insert("config.yaml".to_string(), "key: value".to_string());
 
-        Ok(vec![ConfigMap {
+        let items = vec![ConfigMap {
             metadata: Self::create_metadata(
                 "app-config".to_string(),
                 Some("default".to_string()),
@@ -937,7 +1342,8 @@ impl K8sClient for MockK8sClient {
             ),
             data: Some(data),
             ..Default::default()
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_configmap(&self, name: &str, namespace: &str) -> Result<ConfigMap> {
@@ -962,14 +1368,18 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_secrets(&self, _namespace: Option<&str>) -> Result<Vec<Secret>> {
+    async fn list_secrets(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Secret>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T08:00:00Z")
                 .unwrap()
                 .with_timezone(&chrono::Utc),
         );
 
-        Ok(vec![Secret {
+        let items = vec![Secret {
             metadata: Self::create_metadata(
                 "app-secret".to_string(),
                 Some("default".to_string()),
@@ -980,7 +1390,8 @@ impl K8sClient for MockK8sClient {
             type_: Some("Opaque".to_string()),
             data: Some(BTreeMap::new()),
             ..Default::default()
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_secret(&self, name: &str, namespace: &str) -> Result<Secret> {
@@ -1004,8 +1415,12 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_ingresses(&self, _namespace: Option<&str>) -> Result<Vec<Ingress>> {
-        Ok(vec![])
+    async fn list_ingresses(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Ingress>> {
+        paginate(vec![], query)
     }
 
     async fn get_ingress(&self, _name: &str, _namespace: &str) -> Result<Ingress> {
@@ -1019,8 +1434,12 @@ impl K8sClient for MockK8sClient {
         )))
     }
 
-    async fn list_networkpolicies(&self, _namespace: Option<&str>) -> Result<Vec<NetworkPolicy>> {
-        Ok(vec![])
+    async fn list_networkpolicies(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<NetworkPolicy>> {
+        paginate(vec![], query)
     }
 
     async fn get_networkpolicy(&self, _name: &str, _namespace: &str) -> Result<NetworkPolicy> {
@@ -1034,7 +1453,10 @@ impl K8sClient for MockK8sClient {
         )))
     }
 
-    async fn list_persistentvolumes(&self) -> Result<Vec<PersistentVolume>> {
+    async fn list_persistentvolumes(
+        &self,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<PersistentVolume>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T08:00:00Z")
                 .unwrap()
@@ -1043,7 +1465,7 @@ impl K8sClient for MockK8sClient {
         let mut capacity = BTreeMap::new();
         capacity.insert("storage".to_string(), Quantity("10Gi".to_string()));
 
-        Ok(vec![PersistentVolume {
+        let items = vec![PersistentVolume {
             metadata: Self::create_metadata(
                 "pv-1".to_string(),
                 None,
@@ -1061,7 +1483,8 @@ impl K8sClient for MockK8sClient {
                 phase: Some("Available".to_string()),
                 ..Default::default()
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_persistentvolume(&self, name: &str) -> Result<PersistentVolume> {
@@ -1097,7 +1520,8 @@ impl K8sClient for MockK8sClient {
     async fn list_persistentvolumeclaims(
         &self,
         _namespace: Option<&str>,
-    ) -> Result<Vec<PersistentVolumeClaim>> {
+        query: &ListQuery,
+    ) -> Result<ResourcePage<PersistentVolumeClaim>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T08:00:00Z")
                 .unwrap()
@@ -1106,7 +1530,7 @@ impl K8sClient for MockK8sClient {
         let mut requests = BTreeMap::new();
         requests.insert("storage".to_string(), Quantity("10Gi".to_string()));
 
-        Ok(vec![PersistentVolumeClaim {
+        let items = vec![PersistentVolumeClaim {
             metadata: Self::create_metadata(
                 "db-pvc".to_string(),
                 Some("default".to_string()),
@@ -1126,7 +1550,8 @@ impl K8sClient for MockK8sClient {
                 phase: Some("Bound".to_string()),
                 ..Default::default()
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_persistentvolumeclaim(
@@ -1165,7 +1590,7 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_storageclasses(&self) -> Result<Vec<StorageClass>> {
+    async fn list_storageclasses(&self, query: &ListQuery) -> Result<ResourcePage<StorageClass>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
                 .unwrap()
@@ -1174,7 +1599,7 @@ impl K8sClient for MockK8sClient {
         let mut parameters = BTreeMap::new();
         parameters.insert("type".to_string(), "gp3".to_string());
 
-        Ok(vec![StorageClass {
+        let items = vec![StorageClass {
             metadata: Self::create_metadata(
                 "fast-ssd".to_string(),
                 None,
@@ -1185,7 +1610,8 @@ impl K8sClient for MockK8sClient {
             provisioner: "kubernetes.io/aws-ebs".to_string(),
             parameters: Some(parameters),
             ..Default::default()
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_storageclass(&self, name: &str) -> Result<StorageClass> {
@@ -1211,8 +1637,12 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_roles(&self, _namespace: Option<&str>) -> Result<Vec<Role>> {
-        Ok(vec![])
+    async fn list_roles(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Role>> {
+        paginate(vec![], query)
     }
 
     async fn get_role(&self, _name: &str, _namespace: &str) -> Result<Role> {
@@ -1226,8 +1656,8 @@ impl K8sClient for MockK8sClient {
         )))
     }
 
-    async fn list_clusterroles(&self) -> Result<Vec<ClusterRole>> {
-        Ok(vec![])
+    async fn list_clusterroles(&self, query: &ListQuery) -> Result<ResourcePage<ClusterRole>> {
+        paginate(vec![], query)
     }
 
     async fn get_clusterrole(&self, _name: &str) -> Result<ClusterRole> {
@@ -1241,8 +1671,12 @@ impl K8sClient for MockK8sClient {
         )))
     }
 
-    async fn list_rolebindings(&self, _namespace: Option<&str>) -> Result<Vec<RoleBinding>> {
-        Ok(vec![])
+    async fn list_rolebindings(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<RoleBinding>> {
+        paginate(vec![], query)
     }
 
     async fn get_rolebinding(&self, _name: &str, _namespace: &str) -> Result<RoleBinding> {
@@ -1256,8 +1690,11 @@ impl K8sClient for MockK8sClient {
         )))
     }
 
-    async fn list_clusterrolebindings(&self) -> Result<Vec<ClusterRoleBinding>> {
-        Ok(vec![])
+    async fn list_clusterrolebindings(
+        &self,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<ClusterRoleBinding>> {
+        paginate(vec![], query)
     }
 
     async fn get_clusterrolebinding(&self, _name: &str) -> Result<ClusterRoleBinding> {
@@ -1271,7 +1708,11 @@ impl K8sClient for MockK8sClient {
         )))
     }
 
-    async fn list_serviceaccounts(&self, _namespace: Option<&str>) -> Result<Vec<ServiceAccount>> {
+    async fn list_serviceaccounts(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<ServiceAccount>> {
         let creation_time1 = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
                 .unwrap()
@@ -1283,7 +1724,7 @@ impl K8sClient for MockK8sClient {
                 .with_timezone(&chrono::Utc),
         );
 
-        Ok(vec![
+        let items = vec![
             ServiceAccount {
                 metadata: Self::create_metadata(
                     "default".to_string(),
@@ -1308,7 +1749,8 @@ impl K8sClient for MockK8sClient {
                 image_pull_secrets: None,
                 secrets: None,
             },
-        ])
+        ];
+        paginate(items, query)
     }
 
     async fn get_serviceaccount(&self, name: &str, namespace: &str) -> Result<ServiceAccount> {
@@ -1332,14 +1774,18 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_endpoints(&self, _namespace: Option<&str>) -> Result<Vec<Endpoints>> {
+    async fn list_endpoints(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Endpoints>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T10:00:00Z")
                 .unwrap()
                 .with_timezone(&chrono::Utc),
         );
 
-        Ok(vec![Endpoints {
+        let items = vec![Endpoints {
             metadata: Self::create_metadata(
                 "web-service".to_string(),
                 Some("default".to_string()),
@@ -1370,7 +1816,8 @@ impl K8sClient for MockK8sClient {
                 }]),
                 ..Default::default()
             }]),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_endpoints(&self, name: &str, namespace: &str) -> Result<Endpoints> {
@@ -1406,7 +1853,11 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_events(&self, _namespace: Option<&str>) -> Result<Vec<Event>> {
+    async fn list_events(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Event>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T10:00:00Z")
                 .unwrap()
@@ -1423,7 +1874,7 @@ impl K8sClient for MockK8sClient {
                 .with_timezone(&chrono::Utc),
         );
 
-        Ok(vec![Event {
+        let items = vec![Event {
             metadata: Self::create_metadata(
                 "web-app-1.1234567890".to_string(),
                 Some("default".to_string()),
@@ -1448,7 +1899,8 @@ impl K8sClient for MockK8sClient {
                 host: Some("node-1".to_string()),
             }),
             type_: Some("Normal".to_string()),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_event(&self, name: &str, namespace: &str) -> Result<Event> {
@@ -1499,14 +1951,15 @@ impl K8sClient for MockK8sClient {
     async fn list_horizontalpodautoscalers(
         &self,
         _namespace: Option<&str>,
-    ) -> Result<Vec<HorizontalPodAutoscaler>> {
+        query: &ListQuery,
+    ) -> Result<ResourcePage<HorizontalPodAutoscaler>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T10:00:00Z")
                 .unwrap()
                 .with_timezone(&chrono::Utc),
         );
 
-        Ok(vec![HorizontalPodAutoscaler {
+        let items = vec![HorizontalPodAutoscaler {
             metadata: Self::create_metadata(
                 "web-hpa".to_string(),
                 Some("default".to_string()),
@@ -1529,7 +1982,8 @@ impl K8sClient for MockK8sClient {
                 current_metrics: None,
                 conditions: None,
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_horizontalpodautoscaler(
@@ -1569,7 +2023,11 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_limitranges(&self, _namespace: Option<&str>) -> Result<Vec<LimitRange>> {
+    async fn list_limitranges(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<LimitRange>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T10:00:00Z")
                 .unwrap()
@@ -1579,7 +2037,7 @@ impl K8sClient for MockK8sClient {
         default_requests.insert("cpu".to_string(), Quantity("100m".to_string()));
         default_requests.insert("memory".to_string(), Quantity("128Mi".to_string()));
 
-        Ok(vec![LimitRange {
+        let items = vec![LimitRange {
             metadata: Self::create_metadata(
                 "default-limits".to_string(),
                 Some("default".to_string()),
@@ -1597,7 +2055,8 @@ impl K8sClient for MockK8sClient {
                     max_limit_request_ratio: None,
                 }],
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_limitrange(&self, name: &str, namespace: &str) -> Result<LimitRange> {
@@ -1631,7 +2090,11 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_resourcequotas(&self, _namespace: Option<&str>) -> Result<Vec<ResourceQuota>> {
+    async fn list_resourcequotas(
+        &self,
+        _namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<ResourceQuota>> {
         let creation_time = Time(
             chrono::DateTime::parse_from_rfc3339("2024-01-15T10:00:00Z")
                 .unwrap()
@@ -1642,7 +2105,7 @@ impl K8sClient for MockK8sClient {
         hard.insert("requests.cpu".to_string(), Quantity("4".to_string()));
         hard.insert("requests.memory".to_string(), Quantity("8Gi".to_string()));
 
-        Ok(vec![ResourceQuota {
+        let items = vec![ResourceQuota {
             metadata: Self::create_metadata(
                 "default-quota".to_string(),
                 Some("default".to_string()),
@@ -1659,7 +2122,8 @@ impl K8sClient for MockK8sClient {
                 hard: Some(hard.clone()),
                 used: Some(hard),
             }),
-        }])
+        }];
+        paginate(items, query)
     }
 
     async fn get_resourcequota(&self, name: &str, namespace: &str) -> Result<ResourceQuota> {
@@ -1693,8 +2157,8 @@ impl K8sClient for MockK8sClient {
         })
     }
 
-    async fn list_crds(&self) -> Result<Vec<CustomResourceDefinition>> {
-        Ok(vec![])
+    async fn list_crds(&self, query: &ListQuery) -> Result<ResourcePage<CustomResourceDefinition>> {
+        paginate(vec![], query)
     }
 
     async fn list_custom_resources(
@@ -1704,8 +2168,9 @@ impl K8sClient for MockK8sClient {
         _plural: &str,
         _scope: &str,
         _namespace: Option<&str>,
-    ) -> Result<Vec<Value>> {
-        Ok(vec![])
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Value>> {
+        paginate(vec![], query)
     }
 
     async fn get_custom_resource(
@@ -1720,6 +2185,62 @@ impl K8sClient for MockK8sClient {
         Ok(serde_json::json!({}))
     }
 
+    async fn list_table(
+        &self,
+        kind: &str,
+        namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<Value>> {
+        // Mirrors the default printer columns the API server returns for
+        // resources without their own table handler
+        let page = fetch_resources(self, kind, namespace, query).await?;
+        let rows = page
+            .items
+            .iter()
+            .map(|item| {
+                let metadata = item.get("metadata").cloned().unwrap_or(Value::Null);
+                serde_json::json!({
+                    "cells": [
+                        metadata.get("name").cloned().unwrap_or(Value::Null),
+                        metadata.get("creationTimestamp").cloned().unwrap_or(Value::Null),
+                    ],
+                    "object": { "metadata": metadata },
+                })
+            })
+            .collect();
+        let mut table = page.map_items(|_| rows);
+        table.column_definitions = Some(vec![
+            serde_json::json!({ "name": "Name", "type": "string", "format": "name", "priority": 0 }),
+            serde_json::json!({ "name": "Created At", "type": "date", "format": "", "priority": 0 }),
+        ]);
+        Ok(table)
+    }
+
+    async fn list_metadata(
+        &self,
+        kind: &str,
+        namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<ResourcePage<ObjectMeta>> {
+        // Select on the full objects, then page the metadata
+        let selection = ListQuery::new(query.label_selector.clone(), query.field_selector.clone());
+        let page = fetch_resources(self, kind, namespace, &selection).await?;
+        let items = page
+            .items
+            .into_iter()
+            .map(|item| {
+                serde_json::from_value(item.get("metadata").cloned().unwrap_or_default())
+                    .map_err(K8sError::from)
+            })
+            .collect::<Result<Vec<ObjectMeta>>>()?;
+        let paging = ListQuery {
+            limit: query.limit,
+            continue_token: query.continue_token.clone(),
+            ..Default::default()
+        };
+        paginate(items, &paging)
+    }
+
     async fn apiserver_version(&self) -> Result<k8s_openapi::apimachinery::pkg::version::Info> {
         Ok(k8s_openapi::apimachinery::pkg::version::Info {
             major: "1".to_string(),
@@ -1734,16 +2255,522 @@ impl K8sClient for MockK8sClient {
         })
     }
 
+    async fn api_resources(&self) -> Result<Arc<Vec<ApiResourceInfo>>> {
+        let all_verbs = [
+            "create",
+            "delete",
+            "deletecollection",
+            "get",
+            "list",
+            "patch",
+            "update",
+            "watch",
+        ];
+        let resource = |group: &str, kind: &str, plural: &str, scope: &str| ApiResourceInfo {
+            group: group.to_string(),
+            version: "v1".to_string(),
+            kind: kind.to_string(),
+            plural: plural.to_string(),
+            scope: scope.to_string(),
+            verbs: all_verbs.iter().map(|v| v.to_string()).collect(),
+        };
+        Ok(Arc::new(vec![
+            resource("", "Pod", "pods", "Namespaced"),
+            resource("", "Service", "services", "Namespaced"),
+            resource("", "ConfigMap", "configmaps", "Namespaced"),
+            resource("", "Namespace", "namespaces", "Cluster"),
+            resource("", "Node", "nodes", "Cluster"),
+            resource("apps", "Deployment", "deployments", "Namespaced"),
+            resource("apps", "StatefulSet", "statefulsets", "Namespaced"),
+            resource(
+                "policy",
+                "PodDisruptionBudget",
+                "poddisruptionbudgets",
+                "Namespaced",
+            ),
+            resource(
+                "scheduling.k8s.io",
+                "PriorityClass",
+                "priorityclasses",
+                "Cluster",
+            ),
+            resource(
+                "networking.k8s.io",
+                "IngressClass",
+                "ingressclasses",
+                "Cluster",
+            ),
+            resource("coordination.k8s.io", "Lease", "leases", "Namespaced"),
+            resource("storage.k8s.io", "CSIDriver", "csidrivers", "Cluster"),
+            resource(
+                "storage.k8s.io",
+                "VolumeAttachment",
+                "volumeattachments",
+                "Cluster",
+            ),
+        ]))
+    }
+
     async fn delete_resource(
         &self,
         _kind: &str,
         _name: &str,
         _namespace: Option<&str>,
+        _options: &DeleteOptions,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn evict_pod(
+        &self,
+        _name: &str,
+        _namespace: &str,
+        _options: &DeleteOptions,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn rollout_restart(&self, kind: &str, name: &str, namespace: &str) -> Result<()> {
+        if !matches!(kind, "Deployment" | "StatefulSet" | "DaemonSet") {
+            return Err(unsupported_rollout_kind(kind));
+        }
+        self.state
+            .lock()
+            .map_err(|e| K8sError::Lock(e.to_string()))?
+            .rollout_restarts
+            .push((kind.to_string(), namespace.to_string(), name.to_string()));
+        Ok(())
+    }
+
+    async fn set_rollout_paused(&self, name: &str, namespace: &str, paused: bool) -> Result<()> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| K8sError::Lock(e.to_string()))?;
+        let key = (namespace.to_string(), name.to_string());
+        if paused {
+            state.paused_deployments.insert(key);
+        } else {
+            state.paused_deployments.remove(&key);
+        }
+        Ok(())
+    }
+
+    async fn set_rollout_partition(
+        &self,
+        name: &str,
+        namespace: &str,
+        partition: i32,
+    ) -> Result<()> {
+        self.state
+            .lock()
+            .map_err(|e| K8sError::Lock(e.to_string()))?
+            .rollout_partitions
+            .insert((namespace.to_string(), name.to_string()), partition);
+        Ok(())
+    }
+
+    async fn set_image(
+        &self,
+        kind: &str,
+        name: &str,
+        namespace: &str,
+        images: &[ContainerImage],
+    ) -> Result<()> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| K8sError::Lock(e.to_string()))?;
+        for container in images {
+            state.container_images.insert(
+                (
+                    kind.to_string(),
+                    namespace.to_string(),
+                    name.to_string(),
+                    container.name.clone(),
+                ),
+                container.image.clone(),
+            );
+        }
+        Ok(())
+    }
+
+    async fn trigger_cronjob(&self, name: &str, namespace: &str) -> Result<String> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| K8sError::Lock(e.to_string()))?;
+        state
+            .triggered_cronjobs
+            .push((namespace.to_string(), name.to_string()));
+        Ok(format!(
+            "{}-manual-{}",
+            name,
+            state.triggered_cronjobs.len()
+        ))
+    }
+
+    async fn set_cronjob_suspended(
+        &self,
+        name: &str,
+        namespace: &str,
+        suspended: bool,
     ) -> Result<()> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| K8sError::Lock(e.to_string()))?;
+        let key = (namespace.to_string(), name.to_string());
+        if suspended {
+            state.suspended_cronjobs.insert(key);
+        } else {
+            state.suspended_cronjobs.remove(&key);
+        }
         Ok(())
     }
 
-    async fn rollout_restart_deployment(&self, _name: &str, _namespace: &str) -> Result<()> {
+    async fn set_node_unschedulable(&self, name: &str, unschedulable: bool) -> Result<()> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| K8sError::Lock(e.to_string()))?;
+        if unschedulable {
+            state.cordoned_nodes.insert(name.to_string());
+        } else {
+            state.cordoned_nodes.remove(name);
+        }
         Ok(())
     }
+
+    async fn scale(
+        &self,
+        kind: &str,
+        name: &str,
+        namespace: Option<&str>,
+        replicas: i32,
+    ) -> Result<()> {
+        self.state
+            .lock()
+            .map_err(|e| K8sError::Lock(e.to_string()))?
+            .scaled_replicas
+            .insert(
+                (
+                    kind.to_string(),
+                    namespace.unwrap_or_default().to_string(),
+                    name.to_string(),
+                ),
+                replicas,
+            );
+        Ok(())
+    }
+
+    async fn stream_pod_logs(
+        &self,
+        name: &str,
+        _namespace: &str,
+        options: &PodLogOptions,
+    ) -> Result<LogLineStream> {
+        let container = options.container.as_deref().unwrap_or("main");
+        let mut lines: Vec<String> = (0..20)
+            .map(|i| {
+                let message = format!("[{}/{}] mock log line {}", name, container, i + 1);
+                if options.timestamps {
+                    format!("2024-01-15T10:00:{:02}.000000000Z {}", i, message)
+                } else {
+                    message
+                }
+            })
+            .collect();
+        if let Some(tail) = options.tail_lines {
+            let skip = lines.len().saturating_sub(tail.max(0) as usize);
+            lines.drain(..skip);
+        }
+        Ok(futures::stream::iter(lines.into_iter().map(Ok)).boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kube::ResourceExt;
+
+    #[tokio::test]
+    async fn scale_records_replicas() {
+        let client = MockK8sClient::new();
+        client
+            .scale("Deployment", "web-deployment", Some("default"), 5)
+            .await
+            .unwrap();
+
+        let recorded = client
+            .state()
+            .unwrap()
+            .scaled_replicas
+            .get(&(
+                "Deployment".to_string(),
+                "default".to_string(),
+                "web-deployment".to_string(),
+            ))
+            .copied();
+        assert_eq!(recorded, Some(5));
+        let deployment = client
+            .get_deployment("web-deployment", "default")
+            .await
+            .unwrap();
+        assert_eq!(deployment.spec.unwrap().replicas, Some(5));
+    }
+
+    #[tokio::test]
+    async fn clients_do_not_share_state() {
+        let scaled = MockK8sClient::new();
+        scaled
+            .scale("StatefulSet", "db-statefulset", Some("default"), 1)
+            .await
+            .unwrap();
+
+        let other = MockK8sClient::new();
+        assert!(other.state().unwrap().scaled_replicas.is_empty());
+    }
+
+    fn names<T: kube::Resource>(items: &[T]) -> Vec<String> {
+        items.iter().map(|item| item.name_any()).collect()
+    }
+
+    #[tokio::test]
+    async fn lists_apply_label_selector() {
+        let client = MockK8sClient::new();
+        let list = |selector: &'static str| {
+            let client = &client;
+            async move {
+                client
+                    .list_deployments(None, &ListQuery::new(Some(selector.to_string()), None))
+                    .await
+                    .map(|page| page.items)
+            }
+        };
+        assert_eq!(
+            names(&list("app=api").await.unwrap()),
+            vec!["api-deployment"]
+        );
+        assert_eq!(
+            names(&list("app==web").await.unwrap()),
+            vec!["web-deployment"]
+        );
+        assert_eq!(
+            names(&list("app!=web").await.unwrap()),
+            vec!["api-deployment"]
+        );
+        assert_eq!(list("app").await.unwrap().len(), 2);
+        assert!(list("!app").await.unwrap().is_empty());
+        assert!(list("app in (web,api)").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn lists_apply_field_selector() {
+        let client = MockK8sClient::new();
+        let pods = client
+            .list_pods(
+                None,
+                &ListQuery::new(None, Some("metadata.name!=web-app-1".to_string())),
+            )
+            .await
+            .unwrap()
+            .items;
+        assert_eq!(names(&pods), vec!["api-server-1"]);
+    }
+
+    fn page_query(limit: u32, continue_token: Option<&str>) -> ListQuery {
+        ListQuery {
+            limit: Some(limit),
+            continue_token: continue_token.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn paginate_follows_continue_tokens() {
+        let client = MockK8sClient::new();
+        let first = client
+            .list_deployments(None, &page_query(1, None))
+            .await
+            .unwrap();
+        assert_eq!(names(&first.items), vec!["web-deployment"]);
+        assert_eq!(first.continue_token.as_deref(), Some("1"));
+        assert_eq!(first.remaining_item_count, Some(1));
+
+        let second = client
+            .list_deployments(None, &page_query(1, Some("1")))
+            .await
+            .unwrap();
+        assert_eq!(names(&second.items), vec!["api-deployment"]);
+        assert_eq!(second.continue_token, None);
+        assert_eq!(second.remaining_item_count, None);
+
+        assert!(client
+            .list_deployments(None, &page_query(1, Some("not-a-token")))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn paginate_selects_before_paging() {
+        let client = MockK8sClient::new();
+        let query = ListQuery {
+            field_selector: Some("metadata.name!=web-app-1".to_string()),
+            ..page_query(1, None)
+        };
+        let page = client.list_pods(None, &query).await.unwrap();
+        assert_eq!(names(&page.items), vec!["api-server-1"]);
+        assert_eq!(page.continue_token, None);
+    }
+
+    #[tokio::test]
+    async fn list_metadata_applies_selectors() {
+        let client = MockK8sClient::new();
+        let query = ListQuery::new(
+            Some("app=web".to_string()),
+            Some("metadata.namespace=default".to_string()),
+        );
+        let page = client
+            .list_metadata("Deployment", None, &query)
+            .await
+            .unwrap();
+        let names: Vec<_> = page.items.into_iter().filter_map(|m| m.name).collect();
+        assert_eq!(names, vec!["web-deployment"]);
+    }
+
+    #[tokio::test]
+    async fn rollout_restart_records_calls_in_order() {
+        let client = MockK8sClient::new();
+        client
+            .rollout_restart("Deployment", "web-deployment", "default")
+            .await
+            .unwrap();
+        client
+            .rollout_restart("DaemonSet", "logging-daemonset", "kube-system")
+            .await
+            .unwrap();
+        assert!(client
+            .rollout_restart("Job", "backup-job", "default")
+            .await
+            .is_err());
+
+        assert_eq!(
+            client.state().unwrap().rollout_restarts,
+            vec![
+                (
+                    "Deployment".to_string(),
+                    "default".to_string(),
+                    "web-deployment".to_string()
+                ),
+                (
+                    "DaemonSet".to_string(),
+                    "kube-system".to_string(),
+                    "logging-daemonset".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn pause_and_resume_deployment() {
+        let client = MockK8sClient::new();
+        client
+            .set_rollout_paused("web-deployment", "default", true)
+            .await
+            .unwrap();
+        let paused = client
+            .get_deployment("web-deployment", "default")
+            .await
+            .unwrap();
+        assert_eq!(paused.spec.unwrap().paused, Some(true));
+
+        client
+            .set_rollout_paused("web-deployment", "default", false)
+            .await
+            .unwrap();
+        let resumed = client
+            .get_deployment("web-deployment", "default")
+            .await
+            .unwrap();
+        assert_eq!(resumed.spec.unwrap().paused, None);
+        assert!(client.state().unwrap().paused_deployments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn partition_shows_in_statefulset() {
+        let client = MockK8sClient::new();
+        client
+            .set_rollout_partition("db-statefulset", "default", 2)
+            .await
+            .unwrap();
+
+        let sts = client
+            .get_statefulset("db-statefulset", "default")
+            .await
+            .unwrap();
+        let partition = sts
+            .spec
+            .and_then(|s| s.update_strategy)
+            .and_then(|u| u.rolling_update)
+            .and_then(|r| r.partition);
+        assert_eq!(partition, Some(2));
+    }
+
+    #[tokio::test]
+    async fn set_image_shows_in_pod_template() {
+        let client = MockK8sClient::new();
+        client
+            .set_image(
+                "Deployment",
+                "web-deployment",
+                "default",
+                &[ContainerImage {
+                    name: "web".to_string(),
+                    image: "nginx:1.27".to_string(),
+                    init: false,
+                }],
+            )
+            .await
+            .unwrap();
+
+        let image = |deployment: Deployment| {
+            deployment
+                .spec
+                .and_then(|s| s.template.spec)
+                .and_then(|s| s.containers.into_iter().next())
+                .and_then(|c| c.image)
+        };
+        let changed = client
+            .get_deployment("web-deployment", "default")
+            .await
+            .unwrap();
+        assert_eq!(image(changed), Some("nginx:1.27".to_string()));
+        let untouched = client
+            .get_deployment("api-deployment", "default")
+            .await
+            .unwrap();
+        assert_eq!(image(untouched), Some("nginx:1.21".to_string()));
+    }
+
+    #[tokio::test]
+    async fn unknown_kinds_are_rejected() {
+        let client = MockK8sClient::new();
+        let query = ListQuery::default();
+        assert!(fetch_resources(&client, "Widgets", None, &query)
+            .await
+            .is_err());
+        assert!(
+            crate::k8s_api::fetch_resource_detail(&client, "Widget", "w", Some("default"))
+                .await
+                .is_err()
+        );
+        assert!(fetch_resources(&client, "cr:example.com/v1", None, &query)
+            .await
+            .is_err());
+        // Kinds found through discovery go through the dynamic path
+        assert!(fetch_resources(&client, "PriorityClasses", None, &query)
+            .await
+            .is_ok());
+    }
 }
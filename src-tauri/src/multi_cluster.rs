@@ -0,0 +1,318 @@
+use futures::future::join_all;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::future::Future;
+use std::time::Duration;
+use tauri::State;
+
+use crate::k8s_api::{
+    bad_request, fetch_resource_detail, fetch_resources, get_or_create_client, unknown_kind,
+    K8sClientPool, K8sError, ListQuery, Result,
+};
+
+/// Per-context limit for a fan-out query when the caller does not pass one
+const DEFAULT_CONTEXT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextResource {
+    pub context: String,
+    pub resource: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextError {
+    pub context: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MultiContextResources {
+    pub items: Vec<ContextResource>,
+    pub errors: Vec<ContextError>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextSnapshot {
+    pub context: String,
+    /// The resource after cluster-specific fields were removed
+    pub resource: Option<Value>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldDiff {
+    /// JSON pointer to the field, e.g. "/spec/template/spec/containers/0/image"
+    pub path: String,
+    /// added, removed or changed, relative to the baseline context
+    pub change: String,
+    pub baseline: Option<Value>,
+    pub value: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextFieldDiffs {
+    pub context: String,
+    pub fields: Vec<FieldDiff>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CrossContextDiff {
+    /// The first context that returned the resource; every other context is
+    /// compared against it
+    pub baseline: Option<String>,
+    pub resources: Vec<ContextSnapshot>,
+    pub differences: Vec<ContextFieldDiffs>,
+}
+
+/// Metadata fields that differ between clusters even for identical manifests
+const CLUSTER_METADATA_FIELDS: [&str; 8] = [
+    "uid",
+    "resourceVersion",
+    "managedFields",
+    "creationTimestamp",
+    "deletionTimestamp",
+    "generation",
+    "selfLink",
+    "ownerReferences",
+];
+
+/// Annotations written by controllers and kubectl rather than by the manifest
+const CLUSTER_ANNOTATIONS: [&str; 2] = [
+    "deployment.kubernetes.io/revision",
+    "kubectl.kubernetes.io/last-applied-configuration",
+];
+
+/// Strips fields assigned by the cluster so only the desired state is compared.
+fn normalize_for_comparison(resource: &mut Value) {
+    if let Some(map) = resource.as_object_mut() {
+        map.remove("status");
+    }
+    if let Some(metadata) = resource.get_mut("metadata").and_then(Value::as_object_mut) {
+        for field in CLUSTER_METADATA_FIELDS {
+            metadata.remove(field);
+        }
+        if let Some(annotations) = metadata
+            .get_mut("annotations")
+            .and_then(Value::as_object_mut)
+        {
+            for annotation in CLUSTER_ANNOTATIONS {
+                annotations.remove(annotation);
+            }
+            if annotations.is_empty() {
+                metadata.remove("annotations");
+            }
+        }
+    }
+    if let Some(annotations) = resource
+        .pointer_mut("/spec/template/metadata/annotations")
+        .and_then(Value::as_object_mut)
+    {
+        annotations.remove("kubectl.kubernetes.io/restartedAt");
+    }
+    if let Some(spec) = resource.get_mut("spec").and_then(Value::as_object_mut) {
+        spec.remove("clusterIP");
+        spec.remove("clusterIPs");
+    }
+}
+
+pub(crate) fn field_diffs(baseline: &Value, other: &Value) -> Result<Vec<FieldDiff>> {
+    let patch = serde_json::to_value(json_patch::diff(baseline, other))?;
+    Ok(patch
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|op| {
+            let path = op.get("path")?.as_str()?.to_string();
+            let change = match op.get("op")?.as_str()? {
+                "add" => "added",
+                "remove" => "removed",
+                _ => "changed",
+            };
+            Some(FieldDiff {
+                baseline: baseline.pointer(&path).cloned(),
+                value: other.pointer(&path).cloned(),
+                change: change.to_string(),
+                path,
+            })
+        })
+        .collect())
+}
+
+/// Runs `query` against every context in parallel. Each context gets its own
+/// timeout so one unreachable cluster cannot hold up the others.
+async fn fan_out<T, F, Fut>(
+    contexts: &[String],
+    timeout: Duration,
+    query: F,
+) -> Vec<(String, std::result::Result<T, String>)>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    join_all(contexts.iter().map(|context| {
+        let fut = tokio::time::timeout(timeout, query(context.clone()));
+        async move {
+            let outcome = match fut.await {
+                Ok(Ok(value)) => Ok(value),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(_) => Err(format!("Timed out after {}s", timeout.as_secs())),
+            };
+            if let Err(e) = &outcome {
+                log::warn!("Query against context {} failed: {}", context, e);
+            }
+            (context.clone(), outcome)
+        }
+    }))
+    .await
+}
+
+fn context_timeout(timeout_seconds: Option<u64>) -> Duration {
+    timeout_seconds
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_CONTEXT_TIMEOUT)
+}
+
+/// Lists the same kind, namespace and selectors in several contexts. Rows are tagged with
+/// the context they came from; failing contexts are reported in `errors`.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn list_resources_multi(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    contexts: Vec<String>,
+    kind: String,
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    timeout_seconds: Option<u64>,
+) -> Result<MultiContextResources> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let pool = client_pool.inner();
+    let kc_path = &kc_path;
+    let kind = &kind;
+    let namespace = namespace.as_deref();
+    let query = &ListQuery::new(label_selector, field_selector);
+
+    let results = fan_out(
+        &contexts,
+        context_timeout(timeout_seconds),
+        |context| async move {
+            let client = get_or_create_client(pool, Some(context), kc_path.clone()).await?;
+            fetch_resources(client.as_ref(), kind, namespace, query).await
+        },
+    )
+    .await;
+
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+    for (context, outcome) in results {
+        match outcome {
+            Ok(resources) => {
+                items.extend(resources.items.into_iter().map(|resource| ContextResource {
+                    context: context.clone(),
+                    resource,
+                }))
+            }
+            Err(error) => errors.push(ContextError { context, error }),
+        }
+    }
+    Ok(MultiContextResources { items, errors })
+}
+
+/// Fetches one resource from several contexts and reports, for each context,
+/// the fields that differ from the baseline once cluster-assigned metadata and
+/// status are removed.
+#[tauri::command]
+pub async fn diff_resource_across_contexts(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    contexts: Vec<String>,
+    kind: String,
+    name: String,
+    namespace: Option<String>,
+    timeout_seconds: Option<u64>,
+) -> Result<CrossContextDiff> {
+    let mut seen = HashSet::new();
+    let contexts: Vec<String> = contexts
+        .into_iter()
+        .filter(|context| seen.insert(context.clone()))
+        .collect();
+    if contexts.len() < 2 {
+        return Err(bad_request(
+            "At least two contexts are required for a diff".to_string(),
+        ));
+    }
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let pool = client_pool.inner();
+    let kc_path = &kc_path;
+    let (kind, name) = (&kind, &name);
+    let namespace = namespace.as_deref();
+
+    let results = fan_out(
+        &contexts,
+        context_timeout(timeout_seconds),
+        |context| async move {
+            let client = get_or_create_client(pool, Some(context), kc_path.clone()).await?;
+            let mut resource =
+                fetch_resource_detail(client.as_ref(), kind, name, namespace).await?;
+            normalize_for_comparison(&mut resource);
+            Ok(resource)
+        },
+    )
+    .await;
+
+    let resources: Vec<ContextSnapshot> = results
+        .into_iter()
+        .map(|(context, outcome)| match outcome {
+            Ok(resource) => ContextSnapshot {
+                context,
+                resource: Some(resource),
+                error: None,
+            },
+            Err(error) => ContextSnapshot {
+                context,
+                resource: None,
+                error: Some(error),
+            },
+        })
+        .collect();
+
+    // A kind no context could resolve is a bad request rather than a per-context failure
+    let unknown = unknown_kind(kind).to_string();
+    if resources
+        .iter()
+        .all(|s| s.error.as_deref() == Some(unknown.as_str()))
+    {
+        return Err(unknown_kind(kind));
+    }
+
+    let baseline = resources
+        .iter()
+        .find_map(|s| s.resource.as_ref().map(|r| (s.context.clone(), r)));
+    let mut differences = Vec::new();
+    if let Some((baseline_context, baseline_resource)) = &baseline {
+        for snapshot in &resources {
+            if let Some(resource) = &snapshot.resource {
+                if &snapshot.context != baseline_context {
+                    differences.push(ContextFieldDiffs {
+                        context: snapshot.context.clone(),
+                        fields: field_diffs(baseline_resource, resource)?,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(CrossContextDiff {
+        baseline: baseline.map(|(context, _)| context),
+        resources,
+        differences,
+    })
+}
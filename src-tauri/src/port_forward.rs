@@ -0,0 +1,296 @@
+use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::{Api, ListParams};
+use kube::Client;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, State};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinSet;
+use uuid::Uuid;
+
+use crate::k8s_api::{bad_request, get_or_create_raw_client, K8sClientPool, K8sError, Result};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PortForwardInfo {
+    pub id: String,
+    pub context: Option<String>,
+    pub namespace: String,
+    pub kind: String,
+    pub name: String,
+    pub pod: String,
+    #[serde(rename = "localPort")]
+    pub local_port: u16,
+    #[serde(rename = "remotePort")]
+    pub remote_port: u16,
+}
+
+pub struct PortForward {
+    pub info: PortForwardInfo,
+    pub task: tokio::task::JoinHandle<()>,
+}
+
+pub type PortForwards = Arc<Mutex<HashMap<String, PortForward>>>;
+
+#[derive(Clone, Serialize)]
+struct PortForwardEvent {
+    id: String,
+    status: String,
+    message: String,
+}
+
+fn emit_status(app: &AppHandle, id: &str, status: &str, message: String) {
+    let _ = app.emit(
+        "port-forward-status",
+        PortForwardEvent {
+            id: id.to_string(),
+            status: status.to_string(),
+            message,
+        },
+    );
+}
+
+fn is_pod_ready(pod: &Pod) -> bool {
+    pod.metadata.deletion_timestamp.is_none()
+        && pod
+            .status
+            .as_ref()
+            .and_then(|status| status.conditions.as_ref())
+            .is_some_and(|conditions| {
+                conditions
+                    .iter()
+                    .any(|c| c.type_ == "Ready" && c.status == "True")
+            })
+}
+
+fn find_container_port(pod: &Pod, port_name: &str) -> Option<u16> {
+    pod.spec
+        .as_ref()?
+        .containers
+        .iter()
+        .flat_map(|c| c.ports.iter().flatten())
+        .find(|p| p.name.as_deref() == Some(port_name))
+        .and_then(|p| u16::try_from(p.container_port).ok())
+}
+
+/// Resolves a Service port to a ready backing pod and the matching container
+/// port, the same way `kubectl port-forward svc/...` does.
+async fn resolve_service_target(
+    client: Client,
+    name: &str,
+    namespace: &str,
+    port: u16,
+) -> Result<(String, u16)> {
+    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
+    let service = services.get(name).await?;
+    let spec = service
+        .spec
+        .ok_or_else(|| bad_request(format!("Service {} has no spec", name)))?;
+    let selector = spec
+        .selector
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| bad_request(format!("Service {} has no pod selector", name)))?;
+    let service_port = spec
+        .ports
+        .unwrap_or_default()
+        .into_iter()
+        .find(|p| p.port == i32::from(port))
+        .ok_or_else(|| bad_request(format!("Service {} has no port {}", name, port)))?;
+
+    let label_selector = selector
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join(",");
+    let pods: Api<Pod> = Api::namespaced(client, namespace);
+    let pod = pods
+        .list(&ListParams::default().labels(&label_selector))
+        .await?
+        .items
+        .into_iter()
+        .find(is_pod_ready)
+        .ok_or_else(|| bad_request(format!("No ready pods found for Service {}", name)))?;
+
+    let target_port = match service_port.target_port {
+        Some(IntOrString::Int(p)) => u16::try_from(p).ok(),
+        Some(IntOrString::String(port_name)) => find_container_port(&pod, &port_name),
+        None => Some(port),
+    }
+    .ok_or_else(|| bad_request(format!("Could not resolve target port of Service {}", name)))?;
+
+    Ok((pod.metadata.name.unwrap_or_default(), target_port))
+}
+
+async fn forward_connection(
+    pods: Api<Pod>,
+    pod: String,
+    port: u16,
+    mut socket: TcpStream,
+) -> std::result::Result<(), String> {
+    let mut forwarder = pods
+        .portforward(&pod, &[port])
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut upstream = forwarder
+        .take_stream(port)
+        .ok_or_else(|| format!("Port {} is not available on pod {}", port, pod))?;
+    let result = tokio::io::copy_bidirectional(&mut socket, &mut upstream).await;
+    drop(upstream);
+    forwarder.join().await.map_err(|e| e.to_string())?;
+    result.map(|_| ()).map_err(|e| e.to_string())
+}
+
+async fn run_port_forward(
+    app: AppHandle,
+    forwards: PortForwards,
+    listener: TcpListener,
+    pods: Api<Pod>,
+    id: String,
+    pod: String,
+    port: u16,
+) {
+    // Dropping the set aborts in-flight connections when the forward is stopped.
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((socket, peer)) => {
+                    log::debug!("Port forward {} accepted connection from {}", id, peer);
+                    let app = app.clone();
+                    let id = id.clone();
+                    let pods = pods.clone();
+                    let pod = pod.clone();
+                    connections.spawn(async move {
+                        if let Err(e) = forward_connection(pods, pod, port, socket).await {
+                            log::warn!("Port forward {} connection error: {}", id, e);
+                            emit_status(&app, &id, "error", e);
+                        }
+                    });
+                }
+                Err(e) => {
+                    log::error!("Port forward {} listener error: {}", id, e);
+                    emit_status(&app, &id, "error", e.to_string());
+                    break;
+                }
+            },
+            Some(_) = connections.join_next() => {}
+        }
+    }
+
+    if let Ok(mut forwards) = forwards.lock() {
+        forwards.remove(&id);
+    }
+    emit_status(&app, &id, "stopped", String::new());
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn start_port_forward(
+    app: AppHandle,
+    forwards: State<'_, PortForwards>,
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    kind: String,
+    name: String,
+    namespace: String,
+    remote_port: u16,
+    local_port: Option<u16>,
+) -> Result<PortForwardInfo> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_raw_client(&client_pool, context.clone(), kc_path).await?;
+
+    let (pod, pod_port) = match kind.as_str() {
+        "Pod" => (name.clone(), remote_port),
+        "Service" => resolve_service_target(client.clone(), &name, &namespace, remote_port).await?,
+        _ => {
+            return Err(bad_request(format!(
+                "Unsupported resource kind for port forward: {}",
+                kind
+            )))
+        }
+    };
+
+    let listener = TcpListener::bind(("127.0.0.1", local_port.unwrap_or(0))).await?;
+    let bound_port = listener.local_addr()?.port();
+
+    let id = Uuid::new_v4().to_string();
+    let info = PortForwardInfo {
+        id: id.clone(),
+        context,
+        namespace: namespace.clone(),
+        kind,
+        name,
+        pod: pod.clone(),
+        local_port: bound_port,
+        remote_port: pod_port,
+    };
+    log::info!(
+        "Starting port forward {}: 127.0.0.1:{} -> {}/{}:{}",
+        id,
+        bound_port,
+        namespace,
+        pod,
+        pod_port
+    );
+
+    let pods: Api<Pod> = Api::namespaced(client, &namespace);
+    emit_status(&app, &id, "listening", format!("127.0.0.1:{}", bound_port));
+    // Hold the registry lock across the spawn so the task cannot remove its
+    // entry before it has been inserted.
+    let mut registry = forwards.lock().map_err(|e| K8sError::Lock(e.to_string()))?;
+    let task = tokio::spawn(run_port_forward(
+        app.clone(),
+        forwards.inner().clone(),
+        listener,
+        pods,
+        id.clone(),
+        pod,
+        pod_port,
+    ));
+
+    registry.insert(
+        id,
+        PortForward {
+            info: info.clone(),
+            task,
+        },
+    );
+    Ok(info)
+}
+
+#[tauri::command]
+pub async fn list_port_forwards(
+    forwards: State<'_, PortForwards>,
+    context: Option<String>,
+) -> Result<Vec<PortForwardInfo>> {
+    let forwards = forwards.lock().map_err(|e| K8sError::Lock(e.to_string()))?;
+    let mut result: Vec<PortForwardInfo> = forwards
+        .values()
+        .filter(|f| context.is_none() || f.info.context == context)
+        .map(|f| f.info.clone())
+        .collect();
+    result.sort_by_key(|f| f.local_port);
+    Ok(result)
+}
+
+#[tauri::command]
+pub async fn stop_port_forward(
+    app: AppHandle,
+    forwards: State<'_, PortForwards>,
+    id: String,
+) -> Result<()> {
+    let removed = forwards
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .remove(&id);
+    if let Some(forward) = removed {
+        forward.task.abort();
+        emit_status(&app, &id, "stopped", String::new());
+    }
+    Ok(())
+}
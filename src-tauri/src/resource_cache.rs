@@ -0,0 +1,159 @@
+use kube::runtime::reflector::Store;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::k8s_api::{K8sError, ListQuery, Result};
+
+/// Identifies one shared watch. Panels asking for the same kind, namespace and
+/// selectors in the same context share a single reflector.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    context: Option<String>,
+    kubeconfig_path: Option<String>,
+    kind: String,
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+}
+
+impl CacheKey {
+    pub fn new(
+        context: Option<String>,
+        kubeconfig_path: Option<String>,
+        kind: &str,
+        namespace: Option<&str>,
+        query: &ListQuery,
+    ) -> Self {
+        Self {
+            context,
+            kubeconfig_path,
+            kind: kind.to_string(),
+            namespace: namespace.map(str::to_string),
+            label_selector: query.label_selector.clone(),
+            field_selector: query.field_selector.clone(),
+        }
+    }
+}
+
+/// Watch ids that receive the events of a shared watch
+pub type Subscribers = Arc<Mutex<HashSet<String>>>;
+
+/// Read access to a reflector store regardless of the resource type it holds.
+pub trait StoreSnapshot: Send + Sync {
+    fn snapshot(&self) -> Vec<Value>;
+}
+
+impl<K> StoreSnapshot for Store<K>
+where
+    K: kube::Resource + Clone + Serialize + Send + Sync + 'static,
+    K::DynamicType: Eq + Hash + Clone + Send + Sync,
+{
+    fn snapshot(&self) -> Vec<Value> {
+        self.state()
+            .iter()
+            .filter_map(|obj| serde_json::to_value(obj.as_ref()).ok())
+            .collect()
+    }
+}
+
+/// A reflector task and its store, kept alive while at least one watch uses it.
+pub struct SharedWatch {
+    pub store: Box<dyn StoreSnapshot>,
+    /// Set while the store holds a complete list; cleared when the watch
+    /// errors or starts a relist
+    pub ready: Arc<AtomicBool>,
+    pub subscribers: Subscribers,
+    pub task: tokio::task::JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    watches: HashMap<CacheKey, SharedWatch>,
+    watch_keys: HashMap<String, CacheKey>,
+}
+
+/// Reflector stores shared between `start_watch_resources` callers and used by
+/// `list_resources` when a warm store exists.
+#[derive(Default)]
+pub struct ResourceCache {
+    state: Mutex<CacheState>,
+}
+
+impl ResourceCache {
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, CacheState>> {
+        self.state.lock().map_err(|e| K8sError::Lock(e.to_string()))
+    }
+
+    /// Subscribes `watch_id` to an existing shared watch. Returns false when
+    /// none is running for `key`.
+    pub fn attach(&self, key: &CacheKey, watch_id: &str) -> Result<bool> {
+        let mut state = self.lock()?;
+        let Some(watch) = state.watches.get(key) else {
+            return Ok(false);
+        };
+        watch
+            .subscribers
+            .lock()
+            .map_err(|e| K8sError::Lock(e.to_string()))?
+            .insert(watch_id.to_string());
+        state.watch_keys.insert(watch_id.to_string(), key.clone());
+        Ok(true)
+    }
+
+    /// Registers a newly started shared watch for `watch_id`. If another caller
+    /// started one for the same key in the meantime, the new one is stopped and
+    /// `watch_id` joins the existing watch instead.
+    pub fn insert(&self, key: CacheKey, watch_id: &str, watch: SharedWatch) -> Result<()> {
+        {
+            let mut state = self.lock()?;
+            if !state.watches.contains_key(&key) {
+                state.watch_keys.insert(watch_id.to_string(), key.clone());
+                state.watches.insert(key, watch);
+                return Ok(());
+            }
+        }
+        watch.task.abort();
+        self.attach(&key, watch_id)?;
+        Ok(())
+    }
+
+    /// Unsubscribes `watch_id` and stops the shared watch once nobody uses it.
+    pub fn detach(&self, watch_id: &str) -> Result<()> {
+        let mut state = self.lock()?;
+        let Some(key) = state.watch_keys.remove(watch_id) else {
+            return Ok(());
+        };
+        let unused = match state.watches.get(&key) {
+            Some(watch) => {
+                let mut subscribers = watch
+                    .subscribers
+                    .lock()
+                    .map_err(|e| K8sError::Lock(e.to_string()))?;
+                subscribers.remove(watch_id);
+                subscribers.is_empty()
+            }
+            None => false,
+        };
+        if unused {
+            if let Some(watch) = state.watches.remove(&key) {
+                log::info!("Stopping shared watch for {:?}", key);
+                watch.task.abort();
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the cached objects for `key` once its store has synced.
+    pub fn snapshot(&self, key: &CacheKey) -> Result<Option<Vec<Value>>> {
+        let state = self.lock()?;
+        Ok(state
+            .watches
+            .get(key)
+            .filter(|watch| watch.ready.load(Ordering::Acquire))
+            .map(|watch| watch.store.snapshot()))
+    }
+}
@@ -0,0 +1,1021 @@
+use futures::StreamExt;
+use k8s_openapi::api::apps::v1::{
+    ControllerRevision, DaemonSet, Deployment, ReplicaSet, StatefulSet,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+use kube::api::{Api, ListParams, Patch, PatchParams};
+use kube::runtime::{watcher, WatchStreamExt};
+use kube::Client;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, State};
+use uuid::Uuid;
+
+use crate::k8s_api::{
+    bad_request, fetch_resource_detail, get_or_create_client_for_app, get_or_create_raw_client,
+    ContainerImage, K8sClient, K8sClientPool, K8sError, Result,
+};
+use crate::multi_cluster::{field_diffs, FieldDiff};
+
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+const CHANGE_CAUSE_ANNOTATION: &str = "kubernetes.io/change-cause";
+/// Label the Deployment controller adds to ReplicaSet pod templates
+const POD_TEMPLATE_HASH_LABEL: &str = "pod-template-hash";
+/// Deployment annotations that `kubectl rollout undo` keeps instead of copying
+/// them from the ReplicaSet being rolled back to
+const UNDO_KEPT_ANNOTATIONS: [&str; 5] = [
+    "kubectl.kubernetes.io/last-applied-configuration",
+    REVISION_ANNOTATION,
+    "deployment.kubernetes.io/revision-history",
+    "deployment.kubernetes.io/desired-replicas",
+    "deployment.kubernetes.io/max-replicas",
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RolloutRevision {
+    pub revision: i64,
+    /// Name of the ReplicaSet or ControllerRevision that stores the revision
+    pub source: String,
+    #[serde(rename = "changeCause")]
+    pub change_cause: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Whether the workload currently runs this revision
+    pub current: bool,
+    pub template: Value,
+    /// Changes from the current pod template to this revision's
+    pub diff: Vec<FieldDiff>,
+}
+
+/// A revision as stored in the cluster
+struct StoredRevision {
+    revision: i64,
+    source: String,
+    metadata: ObjectMeta,
+    template: Value,
+    /// ControllerRevision data, a strategic merge patch restoring the revision
+    data: Option<Value>,
+}
+
+struct RolloutState {
+    current_template: Value,
+    /// Ordered from oldest to newest; the newest is the current revision
+    revisions: Vec<StoredRevision>,
+    annotations: BTreeMap<String, String>,
+    paused: bool,
+}
+
+fn is_controlled_by(metadata: &ObjectMeta, uid: &str) -> bool {
+    metadata
+        .owner_references
+        .iter()
+        .flatten()
+        .any(|o| o.controller == Some(true) && o.uid == uid)
+}
+
+fn annotation<'a>(metadata: &'a ObjectMeta, key: &str) -> Option<&'a str> {
+    metadata
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get(key))
+        .map(String::as_str)
+}
+
+fn selector_params(selector: Option<LabelSelector>) -> Result<ListParams> {
+    let Some(selector) = selector else {
+        return Ok(ListParams::default());
+    };
+    let selector = kube::core::Selector::try_from(selector)
+        .map_err(|e| bad_request(format!("Invalid selector: {}", e)))?;
+    Ok(ListParams::default().labels_from(&selector))
+}
+
+async fn deployment_state(client: Client, name: &str, namespace: &str) -> Result<RolloutState> {
+    let deployment: Deployment = Api::namespaced(client.clone(), namespace).get(name).await?;
+    let spec = deployment.spec.unwrap_or_default();
+    let uid = deployment.metadata.uid.unwrap_or_default();
+    let replica_sets: Api<ReplicaSet> = Api::namespaced(client, namespace);
+
+    let mut revisions = Vec::new();
+    for rs in replica_sets
+        .list(&selector_params(Some(spec.selector))?)
+        .await?
+        .items
+    {
+        if !is_controlled_by(&rs.metadata, &uid) {
+            continue;
+        }
+        let Some(revision) =
+            annotation(&rs.metadata, REVISION_ANNOTATION).and_then(|r| r.parse().ok())
+        else {
+            continue;
+        };
+        let mut template =
+            serde_json::to_value(rs.spec.and_then(|s| s.template).unwrap_or_default())?;
+        if let Some(labels) = template
+            .pointer_mut("/metadata/labels")
+            .and_then(Value::as_object_mut)
+        {
+            labels.remove(POD_TEMPLATE_HASH_LABEL);
+        }
+        revisions.push(StoredRevision {
+            revision,
+            source: rs.metadata.name.clone().unwrap_or_default(),
+            metadata: rs.metadata,
+            template,
+            data: None,
+        });
+    }
+    revisions.sort_by_key(|r| r.revision);
+
+    Ok(RolloutState {
+        current_template: serde_json::to_value(spec.template)?,
+        revisions,
+        annotations: deployment.metadata.annotations.unwrap_or_default(),
+        paused: spec.paused.unwrap_or(false),
+    })
+}
+
+/// Reads the ControllerRevisions a StatefulSet or DaemonSet keeps as history.
+async fn controller_revisions(
+    client: Client,
+    namespace: &str,
+    uid: &str,
+    selector: Option<LabelSelector>,
+) -> Result<Vec<StoredRevision>> {
+    let api: Api<ControllerRevision> = Api::namespaced(client, namespace);
+    let mut revisions = Vec::new();
+    for cr in api.list(&selector_params(selector)?).await?.items {
+        if !is_controlled_by(&cr.metadata, uid) {
+            continue;
+        }
+        let data = cr.data.map(|d| d.0).unwrap_or_default();
+        let mut template = data.pointer("/spec/template").cloned().unwrap_or_default();
+        if let Some(template) = template.as_object_mut() {
+            template.remove("$patch");
+        }
+        revisions.push(StoredRevision {
+            revision: cr.revision,
+            source: cr.metadata.name.clone().unwrap_or_default(),
+            metadata: cr.metadata,
+            template,
+            data: Some(data),
+        });
+    }
+    revisions.sort_by_key(|r| r.revision);
+    Ok(revisions)
+}
+
+async fn rollout_state(
+    client: Client,
+    kind: &str,
+    name: &str,
+    namespace: &str,
+) -> Result<RolloutState> {
+    match kind {
+        "Deployment" => deployment_state(client, name, namespace).await,
+        "StatefulSet" => {
+            let sts: StatefulSet = Api::namespaced(client.clone(), namespace).get(name).await?;
+            let spec = sts.spec.unwrap_or_default();
+            let uid = sts.metadata.uid.unwrap_or_default();
+            Ok(RolloutState {
+                revisions: controller_revisions(client, namespace, &uid, Some(spec.selector))
+                    .await?,
+                current_template: serde_json::to_value(spec.template)?,
+                annotations: sts.metadata.annotations.unwrap_or_default(),
+                paused: false,
+            })
+        }
+        "DaemonSet" => {
+            let ds: DaemonSet = Api::namespaced(client.clone(), namespace).get(name).await?;
+            let spec = ds.spec.unwrap_or_default();
+            let uid = ds.metadata.uid.unwrap_or_default();
+            Ok(RolloutState {
+                revisions: controller_revisions(client, namespace, &uid, Some(spec.selector))
+                    .await?,
+                current_template: serde_json::to_value(spec.template)?,
+                annotations: ds.metadata.annotations.unwrap_or_default(),
+                paused: false,
+            })
+        }
+        _ => Err(bad_request(format!(
+            "Unsupported resource kind for rollout history: {}",
+            kind
+        ))),
+    }
+}
+
+/// Restarts the pods of a Deployment, StatefulSet or DaemonSet by stamping
+/// `kubectl.kubernetes.io/restartedAt` on its pod template.
+#[tauri::command]
+pub async fn rollout_restart(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    kind: String,
+    name: String,
+    namespace: String,
+) -> Result<()> {
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    client.rollout_restart(&kind, &name, &namespace).await
+}
+
+/// Pauses a Deployment so template changes do not start a rollout.
+#[tauri::command]
+pub async fn rollout_pause(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
+    namespace: String,
+) -> Result<()> {
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    client.set_rollout_paused(&name, &namespace, true).await
+}
+
+#[tauri::command]
+pub async fn rollout_resume(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
+    namespace: String,
+) -> Result<()> {
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    client.set_rollout_paused(&name, &namespace, false).await
+}
+
+/// Sets the StatefulSet rolling update partition. Only pods with an ordinal at
+/// or above the partition are updated, which allows staged rollouts.
+#[tauri::command]
+pub async fn set_rollout_partition(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    name: String,
+    namespace: String,
+    partition: i32,
+) -> Result<()> {
+    if partition < 0 {
+        return Err(bad_request(format!(
+            "Partition must not be negative: {}",
+            partition
+        )));
+    }
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    client
+        .set_rollout_partition(&name, &namespace, partition)
+        .await
+}
+
+/// JSON pointer to the pod spec of a workload's template
+fn pod_spec_pointer(kind: &str) -> Option<&'static str> {
+    match kind {
+        "Deployment" | "StatefulSet" | "DaemonSet" | "Job" => Some("/spec/template/spec"),
+        "CronJob" => Some("/spec/jobTemplate/spec/template/spec"),
+        _ => None,
+    }
+}
+
+async fn workload_containers(
+    client: &dyn K8sClient,
+    kind: &str,
+    name: &str,
+    namespace: &str,
+) -> Result<Vec<ContainerImage>> {
+    let pointer = pod_spec_pointer(kind)
+        .ok_or_else(|| bad_request(format!("Unsupported resource kind for set image: {}", kind)))?;
+    let resource = fetch_resource_detail(client, kind, name, Some(namespace)).await?;
+    let pod_spec = resource.pointer(pointer).cloned().unwrap_or_default();
+    let containers = |field: &str, init: bool| {
+        pod_spec
+            .get(field)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(move |c| ContainerImage {
+                name: c
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                image: c
+                    .get("image")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                init,
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut result = containers("initContainers", true);
+    result.extend(containers("containers", false));
+    Ok(result)
+}
+
+/// Lists the containers and init containers of a workload's pod template with
+/// their current images.
+#[tauri::command]
+pub async fn list_workload_containers(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    kind: String,
+    name: String,
+    namespace: String,
+) -> Result<Vec<ContainerImage>> {
+    let client = get_or_create_client_for_app(&client_pool, &kubeconfig_path, context).await?;
+    workload_containers(client.as_ref(), &kind, &name, &namespace).await
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SetImageResult {
+    /// The containers that were changed, with their new images
+    pub containers: Vec<ContainerImage>,
+    /// Id of the `rollout-status-{id}` events when the rollout is followed
+    #[serde(rename = "rolloutWatchId")]
+    pub rollout_watch_id: Option<String>,
+}
+
+/// Sets container images on a workload like `kubectl set image`. `images`
+/// maps container names, including init containers, to the new image. With
+/// `watch_rollout` the resulting rollout is followed as with
+/// `watch_rollout_status`; Jobs and CronJobs have no rollout and reject it.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn set_image(
+    app: AppHandle,
+    watches: State<'_, RolloutStatusWatches>,
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    kind: String,
+    name: String,
+    namespace: String,
+    images: HashMap<String, String>,
+    watch_rollout: Option<bool>,
+    timeout_seconds: Option<u64>,
+) -> Result<SetImageResult> {
+    if images.is_empty() {
+        return Err(bad_request("No container images given".to_string()));
+    }
+    let watch_rollout = watch_rollout.unwrap_or(false);
+    if watch_rollout && !matches!(kind.as_str(), "Deployment" | "StatefulSet" | "DaemonSet") {
+        return Err(bad_request(format!(
+            "{} has no rollout to follow; set the image without watch_rollout",
+            kind
+        )));
+    }
+    let client =
+        get_or_create_client_for_app(&client_pool, &kubeconfig_path, context.clone()).await?;
+    let current = workload_containers(client.as_ref(), &kind, &name, &namespace).await?;
+    if let Some(missing) = images
+        .keys()
+        .find(|c| !current.iter().any(|cur| &cur.name == *c))
+    {
+        let available: Vec<&str> = current.iter().map(|c| c.name.as_str()).collect();
+        return Err(bad_request(format!(
+            "Container {} not found in {} {}; available: {}",
+            missing,
+            kind,
+            name,
+            available.join(", ")
+        )));
+    }
+    let changed: Vec<ContainerImage> = current
+        .into_iter()
+        .filter_map(|c| {
+            images.get(&c.name).map(|image| ContainerImage {
+                image: image.clone(),
+                ..c
+            })
+        })
+        .collect();
+
+    // Resolve the watch client first so a failure does not leave the images
+    // changed without the rollout being followed
+    let watch_client = if watch_rollout {
+        let kc_path = kubeconfig_path
+            .lock()
+            .map_err(|e| K8sError::Lock(e.to_string()))?
+            .clone();
+        Some(get_or_create_raw_client(&client_pool, context, kc_path).await?)
+    } else {
+        None
+    };
+
+    client.set_image(&kind, &name, &namespace, &changed).await?;
+    log::info!(
+        "Set images on {} {}/{}: {:?}",
+        kind,
+        namespace,
+        name,
+        changed
+    );
+
+    let rollout_watch_id = match watch_client {
+        Some(raw) => Some(spawn_rollout_status(
+            app,
+            &watches,
+            raw,
+            kind,
+            name,
+            namespace,
+            timeout_seconds,
+        )?),
+        None => None,
+    };
+    Ok(SetImageResult {
+        containers: changed,
+        rollout_watch_id,
+    })
+}
+
+/// Picks the revision to roll back to. Without an explicit revision this is
+/// the one before the current revision, as with `kubectl rollout undo`.
+fn target_revision(
+    revisions: &[StoredRevision],
+    to_revision: Option<i64>,
+) -> Result<&StoredRevision> {
+    match to_revision.filter(|r| *r > 0) {
+        Some(revision) => revisions
+            .iter()
+            .find(|r| r.revision == revision)
+            .ok_or_else(|| bad_request(format!("Revision {} not found", revision))),
+        None => revisions
+            .iter()
+            .rev()
+            .nth(1)
+            .ok_or_else(|| bad_request("No previous revision to roll back to".to_string())),
+    }
+}
+
+/// Strategic merge patch restoring a Deployment's pod template and the
+/// annotations recorded on the ReplicaSet, as `kubectl rollout undo` does.
+fn deployment_undo_patch(state: &RolloutState, target: &StoredRevision) -> Value {
+    let mut annotations = serde_json::Map::new();
+    for key in UNDO_KEPT_ANNOTATIONS {
+        if let Some(value) = state.annotations.get(key) {
+            annotations.insert(key.to_string(), Value::from(value.as_str()));
+        }
+    }
+    for (key, value) in target.metadata.annotations.iter().flatten() {
+        if !UNDO_KEPT_ANNOTATIONS.contains(&key.as_str()) {
+            annotations.insert(key.clone(), Value::from(value.as_str()));
+        }
+    }
+    annotations.insert("$patch".to_string(), Value::from("replace"));
+
+    let mut template = target.template.clone();
+    if let Some(template) = template.as_object_mut() {
+        template.insert("$patch".to_string(), Value::from("replace"));
+    }
+    serde_json::json!({
+        "metadata": { "annotations": annotations },
+        "spec": { "template": template }
+    })
+}
+
+/// Lists the revisions of a Deployment, StatefulSet or DaemonSet from oldest
+/// to newest, each with its pod template and how it differs from the current one.
+#[tauri::command]
+pub async fn get_rollout_history(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    kind: String,
+    name: String,
+    namespace: String,
+) -> Result<Vec<RolloutRevision>> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_raw_client(&client_pool, context, kc_path).await?;
+    let state = rollout_state(client, &kind, &name, &namespace).await?;
+    let current = state.revisions.last().map(|r| r.revision);
+
+    state
+        .revisions
+        .iter()
+        .map(|r| {
+            Ok(RolloutRevision {
+                revision: r.revision,
+                source: r.source.clone(),
+                change_cause: annotation(&r.metadata, CHANGE_CAUSE_ANNOTATION).map(str::to_string),
+                created_at: r
+                    .metadata
+                    .creation_timestamp
+                    .as_ref()
+                    .map(|t| t.0.to_rfc3339()),
+                current: Some(r.revision) == current,
+                diff: field_diffs(&state.current_template, &r.template)?,
+                template: r.template.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Rolls a workload back to `to_revision`, or to the previous revision when
+/// it is unset or 0.
+#[tauri::command]
+pub async fn rollout_undo(
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    kind: String,
+    name: String,
+    namespace: String,
+    to_revision: Option<i64>,
+) -> Result<String> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_raw_client(&client_pool, context, kc_path).await?;
+    let state = rollout_state(client.clone(), &kind, &name, &namespace).await?;
+    if state.paused {
+        return Err(bad_request(format!(
+            "Cannot roll back paused {} {}; resume it first",
+            kind, name
+        )));
+    }
+    let target = target_revision(&state.revisions, to_revision)?;
+    if target.template == state.current_template {
+        return Ok(format!(
+            "Skipped rollback: {} {} already matches revision {}",
+            kind, name, target.revision
+        ));
+    }
+
+    let pp = PatchParams::default();
+    match kind.as_str() {
+        "Deployment" => {
+            let api: Api<Deployment> = Api::namespaced(client, &namespace);
+            api.patch(
+                &name,
+                &pp,
+                &Patch::Strategic(deployment_undo_patch(&state, target)),
+            )
+            .await?;
+        }
+        "StatefulSet" => {
+            let api: Api<StatefulSet> = Api::namespaced(client, &namespace);
+            api.patch(
+                &name,
+                &pp,
+                &Patch::Strategic(target.data.clone().unwrap_or_default()),
+            )
+            .await?;
+        }
+        "DaemonSet" => {
+            let api: Api<DaemonSet> = Api::namespaced(client, &namespace);
+            api.patch(
+                &name,
+                &pp,
+                &Patch::Strategic(target.data.clone().unwrap_or_default()),
+            )
+            .await?;
+        }
+        _ => {
+            return Err(bad_request(format!(
+                "Unsupported resource kind for rollout undo: {}",
+                kind
+            )));
+        }
+    }
+    log::info!(
+        "Rolled back {} {}/{} to revision {}",
+        kind,
+        namespace,
+        name,
+        target.revision
+    );
+    Ok(format!(
+        "Rolled back {} {} to revision {}",
+        kind, name, target.revision
+    ))
+}
+
+pub type RolloutStatusWatches = Arc<Mutex<HashMap<String, tokio::task::JoinHandle<()>>>>;
+
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+struct ReplicaCounts {
+    replicas: i32,
+    updated_replicas: i32,
+    ready_replicas: i32,
+    available_replicas: i32,
+}
+
+#[derive(Clone, Serialize)]
+struct RolloutStatusEvent {
+    /// progress, completed, failed, timeout or error
+    event_type: String,
+    message: String,
+    #[serde(flatten)]
+    counts: ReplicaCounts,
+}
+
+enum RolloutStatus {
+    Progressing(String),
+    Complete(String),
+    /// The Deployment controller gave up, i.e. ProgressDeadlineExceeded
+    Failed(String),
+}
+
+#[derive(Clone)]
+struct RolloutEmitter {
+    app: AppHandle,
+    event_name: String,
+}
+
+impl RolloutEmitter {
+    fn emit(&self, event_type: &str, message: String, counts: ReplicaCounts) {
+        let _ = self.app.emit(
+            &self.event_name,
+            RolloutStatusEvent {
+                event_type: event_type.to_string(),
+                message,
+                counts,
+            },
+        );
+    }
+}
+
+/// True while the controller has not yet seen the latest spec
+fn spec_pending(metadata: &ObjectMeta, observed_generation: Option<i64>) -> bool {
+    metadata.generation.unwrap_or(0) > observed_generation.unwrap_or(0)
+}
+
+/// Mirrors kubectl's Deployment status viewer.
+fn deployment_rollout_status(
+    deployment: &Deployment,
+) -> std::result::Result<(RolloutStatus, ReplicaCounts), String> {
+    let name = deployment.metadata.name.as_deref().unwrap_or_default();
+    let status = deployment.status.clone().unwrap_or_default();
+    let counts = ReplicaCounts {
+        replicas: deployment
+            .spec
+            .as_ref()
+            .and_then(|s| s.replicas)
+            .unwrap_or(1),
+        updated_replicas: status.updated_replicas.unwrap_or(0),
+        ready_replicas: status.ready_replicas.unwrap_or(0),
+        available_replicas: status.available_replicas.unwrap_or(0),
+    };
+    if spec_pending(&deployment.metadata, status.observed_generation) {
+        return Ok((
+            RolloutStatus::Progressing(
+                "Waiting for deployment spec update to be observed".to_string(),
+            ),
+            counts,
+        ));
+    }
+    let deadline_exceeded = status.conditions.iter().flatten().any(|c| {
+        c.type_ == "Progressing" && c.reason.as_deref() == Some("ProgressDeadlineExceeded")
+    });
+    let outcome = if deadline_exceeded {
+        RolloutStatus::Failed(format!(
+            "Deployment {} exceeded its progress deadline",
+            name
+        ))
+    } else if counts.updated_replicas < counts.replicas {
+        RolloutStatus::Progressing(format!(
+            "Waiting for deployment {} rollout to finish: {} out of {} new replicas have been updated",
+            name, counts.updated_replicas, counts.replicas
+        ))
+    } else if status.replicas.unwrap_or(0) > counts.updated_replicas {
+        RolloutStatus::Progressing(format!(
+            "Waiting for deployment {} rollout to finish: {} old replicas are pending termination",
+            name,
+            status.replicas.unwrap_or(0) - counts.updated_replicas
+        ))
+    } else if counts.available_replicas < counts.updated_replicas {
+        RolloutStatus::Progressing(format!(
+            "Waiting for deployment {} rollout to finish: {} of {} updated replicas are available",
+            name, counts.available_replicas, counts.updated_replicas
+        ))
+    } else {
+        RolloutStatus::Complete(format!("Deployment {} successfully rolled out", name))
+    };
+    Ok((outcome, counts))
+}
+
+/// Mirrors kubectl's StatefulSet status viewer, including partitioned rollouts.
+fn stateful_set_rollout_status(
+    sts: &StatefulSet,
+) -> std::result::Result<(RolloutStatus, ReplicaCounts), String> {
+    let spec = sts.spec.clone().unwrap_or_default();
+    let strategy = spec.update_strategy.unwrap_or_default();
+    if strategy.type_.as_deref().unwrap_or("RollingUpdate") != "RollingUpdate" {
+        return Err("Rollout status is only available for the RollingUpdate strategy".to_string());
+    }
+    let status = sts.status.clone().unwrap_or_default();
+    let counts = ReplicaCounts {
+        replicas: spec.replicas.unwrap_or(1),
+        updated_replicas: status.updated_replicas.unwrap_or(0),
+        ready_replicas: status.ready_replicas.unwrap_or(0),
+        available_replicas: status.available_replicas.unwrap_or(0),
+    };
+    if status.observed_generation.is_none()
+        || spec_pending(&sts.metadata, status.observed_generation)
+    {
+        return Ok((
+            RolloutStatus::Progressing(
+                "Waiting for statefulset spec update to be observed".to_string(),
+            ),
+            counts,
+        ));
+    }
+    if counts.ready_replicas < counts.replicas {
+        return Ok((
+            RolloutStatus::Progressing(format!(
+                "Waiting for {} pods to be ready",
+                counts.replicas - counts.ready_replicas
+            )),
+            counts,
+        ));
+    }
+    if let Some(partition) = strategy.rolling_update.and_then(|r| r.partition) {
+        let expected = (counts.replicas - partition).max(0);
+        let outcome = if counts.updated_replicas < expected {
+            RolloutStatus::Progressing(format!(
+                "Waiting for partitioned roll out to finish: {} out of {} new pods have been updated",
+                counts.updated_replicas, expected
+            ))
+        } else {
+            RolloutStatus::Complete(format!(
+                "Partitioned roll out complete: {} new pods have been updated",
+                counts.updated_replicas
+            ))
+        };
+        return Ok((outcome, counts));
+    }
+    let update_revision = status.update_revision.unwrap_or_default();
+    let outcome = if status.current_revision.as_deref() != Some(update_revision.as_str()) {
+        RolloutStatus::Progressing(format!(
+            "Waiting for statefulset rolling update to complete: {} pods at revision {}",
+            counts.updated_replicas, update_revision
+        ))
+    } else {
+        RolloutStatus::Complete(format!(
+            "Statefulset rolling update complete: {} pods at revision {}",
+            status.current_replicas.unwrap_or(0),
+            update_revision
+        ))
+    };
+    Ok((outcome, counts))
+}
+
+/// Mirrors kubectl's DaemonSet status viewer.
+fn daemon_set_rollout_status(
+    ds: &DaemonSet,
+) -> std::result::Result<(RolloutStatus, ReplicaCounts), String> {
+    let name = ds.metadata.name.as_deref().unwrap_or_default();
+    let strategy = ds
+        .spec
+        .as_ref()
+        .and_then(|s| s.update_strategy.as_ref())
+        .and_then(|s| s.type_.as_deref())
+        .unwrap_or("RollingUpdate");
+    if strategy != "RollingUpdate" {
+        return Err("Rollout status is only available for the RollingUpdate strategy".to_string());
+    }
+    let status = ds.status.clone().unwrap_or_default();
+    let counts = ReplicaCounts {
+        replicas: status.desired_number_scheduled,
+        updated_replicas: status.updated_number_scheduled.unwrap_or(0),
+        ready_replicas: status.number_ready,
+        available_replicas: status.number_available.unwrap_or(0),
+    };
+    if spec_pending(&ds.metadata, status.observed_generation) {
+        return Ok((
+            RolloutStatus::Progressing(
+                "Waiting for daemon set spec update to be observed".to_string(),
+            ),
+            counts,
+        ));
+    }
+    let outcome = if counts.updated_replicas < counts.replicas {
+        RolloutStatus::Progressing(format!(
+            "Waiting for daemon set {} rollout to finish: {} out of {} new pods have been updated",
+            name, counts.updated_replicas, counts.replicas
+        ))
+    } else if counts.available_replicas < counts.replicas {
+        RolloutStatus::Progressing(format!(
+            "Waiting for daemon set {} rollout to finish: {} of {} updated pods are available",
+            name, counts.available_replicas, counts.replicas
+        ))
+    } else {
+        RolloutStatus::Complete(format!("Daemon set {} successfully rolled out", name))
+    };
+    Ok((outcome, counts))
+}
+
+/// Watches one object and emits a progress event whenever its rollout status
+/// message changes, until the rollout completes or fails. The counts of the
+/// latest observation are kept in `last_counts`.
+async fn follow_rollout<K>(
+    api: Api<K>,
+    name: &str,
+    evaluate: fn(&K) -> std::result::Result<(RolloutStatus, ReplicaCounts), String>,
+    emitter: &RolloutEmitter,
+    last_counts: &Mutex<ReplicaCounts>,
+) -> std::result::Result<(), String>
+where
+    K: kube::Resource<DynamicType = ()>
+        + Clone
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug
+        + Send
+        + 'static,
+{
+    use kube::runtime::watcher::Event;
+    // The watcher would wait silently for an object that does not exist
+    if api
+        .get_opt(name)
+        .await
+        .map_err(|e| e.to_string())?
+        .is_none()
+    {
+        return Err(format!("{} {} not found", K::kind(&()), name));
+    }
+    let config = watcher::Config::default().fields(&format!("metadata.name={}", name));
+    let stream = watcher(api, config).default_backoff();
+    futures::pin_mut!(stream);
+    let mut last_message = String::new();
+    while let Some(event) = stream.next().await {
+        let obj = match event {
+            Ok(Event::Apply(obj)) | Ok(Event::InitApply(obj)) => obj,
+            Ok(Event::Delete(_)) => return Err(format!("{} was deleted", name)),
+            Ok(Event::Init) | Ok(Event::InitDone) => continue,
+            Err(e) => {
+                log::warn!("Rollout status watch error (will retry): {}", e);
+                continue;
+            }
+        };
+        let (status, counts) = evaluate(&obj)?;
+        if let Ok(mut last) = last_counts.lock() {
+            *last = counts;
+        }
+        match status {
+            RolloutStatus::Progressing(message) => {
+                if message != last_message {
+                    emitter.emit("progress", message.clone(), counts);
+                    last_message = message;
+                }
+            }
+            RolloutStatus::Complete(message) => {
+                emitter.emit("completed", message, counts);
+                return Ok(());
+            }
+            RolloutStatus::Failed(message) => {
+                emitter.emit("failed", message, counts);
+                return Ok(());
+            }
+        }
+    }
+    Err(format!("Watch of {} ended unexpectedly", name))
+}
+
+async fn run_rollout_status(
+    client: Client,
+    kind: &str,
+    name: &str,
+    namespace: &str,
+    emitter: &RolloutEmitter,
+    last_counts: &Mutex<ReplicaCounts>,
+) -> std::result::Result<(), String> {
+    match kind {
+        "Deployment" => {
+            let api: Api<Deployment> = Api::namespaced(client, namespace);
+            follow_rollout(api, name, deployment_rollout_status, emitter, last_counts).await
+        }
+        "StatefulSet" => {
+            let api: Api<StatefulSet> = Api::namespaced(client, namespace);
+            follow_rollout(api, name, stateful_set_rollout_status, emitter, last_counts).await
+        }
+        "DaemonSet" => {
+            let api: Api<DaemonSet> = Api::namespaced(client, namespace);
+            follow_rollout(api, name, daemon_set_rollout_status, emitter, last_counts).await
+        }
+        _ => Err(format!(
+            "Unsupported resource kind for rollout status: {}",
+            kind
+        )),
+    }
+}
+
+/// Follows the rollout of a Deployment, StatefulSet or DaemonSet like
+/// `kubectl rollout status`. Progress is emitted as `rollout-status-{watchId}`
+/// events; the watch ends with a `completed`, `failed`, `timeout` or `error`
+/// event and can be cancelled with `stop_rollout_status`.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn watch_rollout_status(
+    app: AppHandle,
+    watches: State<'_, RolloutStatusWatches>,
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: State<'_, crate::KubeconfigPath>,
+    context: Option<String>,
+    kind: String,
+    name: String,
+    namespace: String,
+    timeout_seconds: Option<u64>,
+) -> Result<String> {
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .clone();
+    let client = get_or_create_raw_client(&client_pool, context, kc_path).await?;
+    spawn_rollout_status(
+        app,
+        &watches,
+        client,
+        kind,
+        name,
+        namespace,
+        timeout_seconds,
+    )
+}
+
+fn spawn_rollout_status(
+    app: AppHandle,
+    watches: &RolloutStatusWatches,
+    client: Client,
+    kind: String,
+    name: String,
+    ns: String,
+    timeout_seconds: Option<u64>,
+) -> Result<String> {
+    let watch_id = Uuid::new_v4().to_string();
+    log::info!(
+        "Watching rollout of {} {}/{}, watch_id: {}",
+        kind,
+        ns,
+        name,
+        watch_id
+    );
+
+    let emitter = RolloutEmitter {
+        app,
+        event_name: format!("rollout-status-{}", watch_id),
+    };
+    let watches_handle = watches.clone();
+    let id = watch_id.clone();
+    // Held while spawning so a watch that ends at once cannot remove itself
+    // before it is registered
+    let mut registry = watches.lock().map_err(|e| K8sError::Lock(e.to_string()))?;
+    let handle = tokio::spawn(async move {
+        let last_counts = Mutex::new(ReplicaCounts::default());
+        let rollout = run_rollout_status(client, &kind, &name, &ns, &emitter, &last_counts);
+        let outcome = match timeout_seconds {
+            Some(secs) => tokio::time::timeout(Duration::from_secs(secs), rollout)
+                .await
+                .unwrap_or_else(|_| {
+                    emitter.emit(
+                        "timeout",
+                        format!(
+                            "Rollout of {} {} did not finish within {}s",
+                            kind, name, secs
+                        ),
+                        last_counts.lock().map(|c| *c).unwrap_or_default(),
+                    );
+                    Ok(())
+                }),
+            None => rollout.await,
+        };
+        if let Err(message) = outcome {
+            log::warn!("Rollout status of {} {} failed: {}", kind, name, message);
+            emitter.emit(
+                "error",
+                message,
+                last_counts.lock().map(|c| *c).unwrap_or_default(),
+            );
+        }
+        if let Ok(mut watches) = watches_handle.lock() {
+            watches.remove(&id);
+        }
+    });
+
+    registry.insert(watch_id.clone(), handle);
+    Ok(watch_id)
+}
+
+#[tauri::command]
+pub async fn stop_rollout_status(
+    watches: State<'_, RolloutStatusWatches>,
+    watch_id: String,
+) -> Result<()> {
+    if let Some(handle) = watches
+        .lock()
+        .map_err(|e| K8sError::Lock(e.to_string()))?
+        .remove(&watch_id)
+    {
+        handle.abort();
+    }
+    Ok(())
+}
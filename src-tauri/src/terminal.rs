@@ -1,21 +1,63 @@
-use portable_pty::{native_pty_system, Child, CommandBuilder, PtySize};
+use futures::channel::mpsc::Sender;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{Api, AttachParams, TerminalSize};
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
 use std::collections::HashMap;
 use std::fs;
 use std::io::{Read, Write};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use tauri::{Emitter, State};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
+use crate::k8s_api::K8sClientPool;
 use crate::Error;
 
+/// Shell used for exec sessions when no command is given: prefer bash and
+/// fall back to sh for minimal images.
+const DEFAULT_EXEC_COMMAND: [&str; 3] = [
+    "/bin/sh",
+    "-c",
+    "TERM=xterm-256color; export TERM; [ -x /bin/bash ] && exec /bin/bash || exec /bin/sh",
+];
+
+pub enum TerminalProcess {
+    /// Local shell running in a PTY
+    Local {
+        child: Arc<Mutex<Box<dyn Child + Send + Sync>>>,
+        master: Arc<Mutex<Box<dyn MasterPty + Send>>>,
+    },
+    /// Process running inside a container via the Kubernetes exec API
+    Exec {
+        task: tokio::task::JoinHandle<()>,
+        resize_tx: Option<Sender<TerminalSize>>,
+    },
+}
+
 pub struct TerminalSession {
     pub writer: Arc<Mutex<Box<dyn Write + Send>>>,
-    pub reader: Arc<Mutex<Box<dyn Read + Send>>>,
-    pub child: Arc<Mutex<Box<dyn Child + Send + Sync>>>,
+    pub process: TerminalProcess,
     pub temp_kubeconfig: Option<PathBuf>,
 }
 
+/// Forwards terminal input to the exec task, which owns the websocket stdin.
+struct ExecStdinWriter(mpsc::UnboundedSender<Vec<u8>>);
+
+impl Write for ExecStdinWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.send(buf.to_vec()).map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::BrokenPipe, "Exec session has ended")
+        })?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 pub type TerminalSessions = Arc<Mutex<HashMap<String, TerminalSession>>>;
 
 fn create_temp_kubeconfig(
@@ -79,6 +121,16 @@ fn create_temp_kubeconfig(
     Ok(temp_file)
 }
 
+/// Notifies the frontend that the shell or remote process behind a session has exited.
+fn emit_terminal_exit(app_handle: &tauri::AppHandle, session_id: &str) {
+    let _ = app_handle.emit(
+        "terminal-exit",
+        serde_json::json!({
+            "session_id": session_id
+        }),
+    );
+}
+
 #[tauri::command]
 pub async fn create_terminal_session(
     sessions: State<'_, TerminalSessions>,
@@ -163,7 +215,7 @@ pub async fn create_terminal_session(
     drop(pty_pair.slave);
     log::debug!("Shell spawned successfully");
 
-    let reader = pty_pair
+    let mut reader = pty_pair
         .master
         .try_clone_reader()
         .map_err(|e| Error::Terminal(format!("Failed to clone reader: {}", e)))?;
@@ -177,20 +229,21 @@ pub async fn create_terminal_session(
     // On Windows, dropping the master closes the ConPTY (ClosePseudoConsole),
     // which invalidates all associated pipe handles including the cloned reader.
     // On Unix this is harmless since dup()'d fds are independent.
-    let master = pty_pair.master;
+    let master = Arc::new(Mutex::new(pty_pair.master));
 
     let writer = Arc::new(Mutex::new(Box::new(writer) as Box<dyn Write + Send>));
 
     let session = TerminalSession {
         writer,
-        reader: Arc::new(Mutex::new(reader)),
-        child: Arc::new(Mutex::new(child)),
+        process: TerminalProcess::Local {
+            child: Arc::new(Mutex::new(child)),
+            master: master.clone(),
+        },
         temp_kubeconfig,
     };
 
     // Start reading from terminal in background
     let session_id_clone = session_id.clone();
-    let reader_clone = session.reader.clone();
     let app_handle_clone = app_handle.clone();
 
     let _read_task = tokio::task::spawn_blocking(move || {
@@ -204,17 +257,7 @@ pub async fn create_terminal_session(
         );
         let mut buffer = [0u8; 4096];
         loop {
-            let read_result = {
-                match reader_clone.lock() {
-                    Ok(mut reader) => reader.read(&mut buffer),
-                    Err(e) => {
-                        log::error!("Terminal reader lock error: {}", e);
-                        break;
-                    }
-                }
-            };
-
-            match read_result {
+            match reader.read(&mut buffer) {
                 Ok(0) => {
                     log::debug!("Terminal reader EOF for session {}", session_id_clone);
                     break;
@@ -243,6 +286,7 @@ pub async fn create_terminal_session(
             "Terminal reader task ended for session {}",
             session_id_clone
         );
+        emit_terminal_exit(&app_handle_clone, &session_id_clone);
     });
 
     sessions
@@ -253,6 +297,130 @@ pub async fn create_terminal_session(
     Ok(session_id)
 }
 
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn create_exec_session(
+    sessions: State<'_, TerminalSessions>,
+    client_pool: State<'_, K8sClientPool>,
+    kubeconfig_path: tauri::State<'_, crate::KubeconfigPath>,
+    app_handle: tauri::AppHandle,
+    context_name: Option<String>,
+    pod_name: String,
+    namespace: String,
+    container: Option<String>,
+    command: Option<Vec<String>>,
+) -> Result<String, Error> {
+    if crate::k8s_api::use_mock() {
+        return Err(Error::Terminal(
+            "Exec is not available with the mock client".to_string(),
+        ));
+    }
+    let kc_path = kubeconfig_path
+        .lock()
+        .map_err(|e| Error::Lock(e.to_string()))?
+        .clone();
+    let client =
+        crate::k8s_api::get_or_create_raw_client(&client_pool, context_name, kc_path).await?;
+    let api: Api<Pod> = Api::namespaced(client, &namespace);
+
+    let mut params = AttachParams::interactive_tty();
+    if let Some(ref container) = container {
+        params = params.container(container.clone());
+    }
+    let command = command
+        .filter(|c| !c.is_empty())
+        .unwrap_or_else(|| DEFAULT_EXEC_COMMAND.iter().map(|s| s.to_string()).collect());
+
+    log::debug!(
+        "Starting exec session: pod={}/{}, container={:?}, command={:?}",
+        namespace,
+        pod_name,
+        container,
+        command
+    );
+    let mut attached = api
+        .exec(&pod_name, command, &params)
+        .await
+        .map_err(|e| Error::Terminal(format!("Failed to exec into pod: {}", e)))?;
+
+    let mut stdin = attached
+        .stdin()
+        .ok_or_else(|| Error::Terminal("Exec session has no stdin".to_string()))?;
+    let mut stdout = attached
+        .stdout()
+        .ok_or_else(|| Error::Terminal("Exec session has no stdout".to_string()))?;
+
+    let resize_tx = attached.terminal_size();
+
+    let session_id = Uuid::new_v4().to_string();
+    let (input_tx, mut input_rx) = mpsc::unbounded_channel::<Vec<u8>>();
+
+    // Hold the registry lock across the spawn so the task cannot remove its
+    // session before it has been inserted.
+    let sessions_registry = sessions.inner().clone();
+    let mut sessions = sessions.lock().map_err(|e| Error::Lock(e.to_string()))?;
+    let session_id_clone = session_id.clone();
+    let task = tokio::spawn(async move {
+        let input = async {
+            while let Some(data) = input_rx.recv().await {
+                if stdin.write_all(&data).await.is_err() {
+                    break;
+                }
+            }
+        };
+        let output = async {
+            let mut buffer = [0u8; 4096];
+            loop {
+                match stdout.read(&mut buffer).await {
+                    Ok(0) => {
+                        log::debug!("Exec session EOF for session {}", session_id_clone);
+                        break;
+                    }
+                    Ok(n) => {
+                        let output = String::from_utf8_lossy(&buffer[..n]).to_string();
+                        let _ = app_handle.emit(
+                            "terminal-output",
+                            serde_json::json!({
+                                "session_id": session_id_clone,
+                                "data": output
+                            }),
+                        );
+                    }
+                    Err(e) => {
+                        log::error!(
+                            "Exec session read error for session {}: {}",
+                            session_id_clone,
+                            e
+                        );
+                        break;
+                    }
+                }
+            }
+        };
+        // Input ends when the session is closed, output when the process exits.
+        tokio::select! {
+            _ = input => {}
+            _ = output => {}
+        }
+        attached.abort();
+        if let Ok(mut sessions) = sessions_registry.lock() {
+            sessions.remove(&session_id_clone);
+        }
+        emit_terminal_exit(&app_handle, &session_id_clone);
+    });
+
+    let session = TerminalSession {
+        writer: Arc::new(Mutex::new(
+            Box::new(ExecStdinWriter(input_tx)) as Box<dyn Write + Send>
+        )),
+        process: TerminalProcess::Exec { task, resize_tx },
+        temp_kubeconfig: None,
+    };
+    sessions.insert(session_id.clone(), session);
+
+    Ok(session_id)
+}
+
 // Write user input data to shell session
 #[tauri::command]
 pub async fn write_to_terminal(
@@ -289,6 +457,51 @@ pub async fn write_to_terminal(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn resize_terminal(
+    sessions: State<'_, TerminalSessions>,
+    session_id: String,
+    rows: u16,
+    cols: u16,
+) -> Result<(), Error> {
+    if rows == 0 || cols == 0 {
+        return Ok(());
+    }
+    let mut sessions = sessions.lock().map_err(|e| Error::Lock(e.to_string()))?;
+    let session = sessions
+        .get_mut(&session_id)
+        .ok_or_else(|| Error::Terminal("Session not found".to_string()))?;
+    match &mut session.process {
+        TerminalProcess::Local { master, .. } => {
+            master
+                .lock()
+                .map_err(|e| Error::Lock(e.to_string()))?
+                .resize(PtySize {
+                    rows,
+                    cols,
+                    pixel_width: 0,
+                    pixel_height: 0,
+                })
+                .map_err(|e| Error::Terminal(format!("Failed to resize PTY: {}", e)))?;
+        }
+        TerminalProcess::Exec { resize_tx, .. } => {
+            if let Some(tx) = resize_tx {
+                // A full channel only means an earlier resize is still pending,
+                // and the next one will supersede it anyway.
+                if let Err(e) = tx.try_send(TerminalSize {
+                    width: cols,
+                    height: rows,
+                }) {
+                    if e.is_disconnected() {
+                        return Err(Error::Terminal("Exec session has ended".to_string()));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn close_terminal_session(
     sessions: State<'_, TerminalSessions>,
@@ -296,9 +509,15 @@ pub async fn close_terminal_session(
 ) -> Result<(), Error> {
     let mut sessions = sessions.lock().map_err(|e| Error::Lock(e.to_string()))?;
     if let Some(session) = sessions.remove(&session_id) {
-        // Kill the child process
-        if let Ok(mut child) = session.child.lock() {
-            let _ = child.kill();
+        match session.process {
+            // Kill the child process
+            TerminalProcess::Local { child, .. } => {
+                if let Ok(mut child) = child.lock() {
+                    let _ = child.kill();
+                }
+            }
+            // Dropping the websocket terminates the remote process
+            TerminalProcess::Exec { task, .. } => task.abort(),
         }
         // Clean up temp kubeconfig file
         if let Some(kubeconfig_path) = session.temp_kubeconfig {
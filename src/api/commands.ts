@@ -35,6 +35,228 @@ export interface CrdGroup {
   resources: CrdResourceInfo[];
 }
 
+export interface ListQuery {
+  labelSelector?: string;
+  fieldSelector?: string;
+}
+
+export interface PageQuery extends ListQuery {
+  limit?: number;
+  continueToken?: string;
+}
+
+export interface ResourcePage<T = any> {
+  items: T[];
+  continue: string | null;
+  remainingItemCount: number | null;
+  columnDefinitions: TableColumnDefinition[] | null;
+}
+
+export interface TableColumnDefinition {
+  name: string;
+  type: string;
+  format: string;
+  description?: string;
+  priority: number;
+}
+
+export interface TableRow {
+  cells: any[];
+  object?: { metadata?: any };
+}
+
+export interface ApiResourceInfo {
+  group: string;
+  version: string;
+  kind: string;
+  plural: string;
+  scope: string;
+  verbs: string[];
+}
+
+export interface PodLogOptions {
+  container?: string;
+  follow?: boolean;
+  tailLines?: number;
+  sinceSeconds?: number;
+  timestamps?: boolean;
+  previous?: boolean;
+}
+
+export interface PodLogEvent {
+  event_type: 'line' | 'error' | 'end';
+  data: string;
+}
+
+export interface WorkloadLogEvent {
+  event_type: 'line' | 'attached' | 'detached' | 'error';
+  pod: string;
+  container: string;
+  data: string;
+}
+
+export interface PortForwardInfo {
+  id: string;
+  context: string | null;
+  namespace: string;
+  kind: string;
+  name: string;
+  pod: string;
+  localPort: number;
+  remotePort: number;
+}
+
+export interface PortForwardEvent {
+  id: string;
+  status: 'listening' | 'error' | 'stopped';
+  message: string;
+}
+
+export interface DeleteOptions {
+  gracePeriodSeconds?: number;
+  propagationPolicy?: 'Foreground' | 'Background' | 'Orphan';
+  dryRun?: boolean;
+  force?: boolean;
+}
+
+export interface ContextResource {
+  context: string;
+  resource: any;
+}
+
+export interface ContextError {
+  context: string;
+  error: string;
+}
+
+export interface MultiContextResources {
+  items: ContextResource[];
+  errors: ContextError[];
+}
+
+export interface ContextSnapshot {
+  context: string;
+  resource: any | null;
+  error: string | null;
+}
+
+export interface FieldDiff {
+  path: string;
+  change: 'added' | 'removed' | 'changed';
+  baseline: any | null;
+  value: any | null;
+}
+
+export interface CrossContextDiff {
+  baseline: string | null;
+  resources: ContextSnapshot[];
+  differences: { context: string; fields: FieldDiff[] }[];
+}
+
+export interface ResourceTarget {
+  kind: string;
+  name: string;
+  namespace?: string;
+}
+
+export interface BulkResult {
+  kind: string;
+  name: string;
+  namespace: string | null;
+  success: boolean;
+  error: string | null;
+}
+
+export interface DrainOptions {
+  gracePeriodSeconds?: number;
+  timeoutSeconds?: number;
+  deleteEmptyDirData?: boolean;
+  force?: boolean;
+}
+
+export interface NodeDrainEvent {
+  event_type:
+    | 'cordoned'
+    | 'skipped'
+    | 'evicting'
+    | 'blocked'
+    | 'evicted'
+    | 'failed'
+    | 'completed'
+    | 'error';
+  pod: string | null;
+  namespace: string | null;
+  message: string;
+}
+
+export interface RolloutRevision {
+  revision: number;
+  source: string;
+  changeCause: string | null;
+  createdAt: string | null;
+  current: boolean;
+  template: any;
+  diff: FieldDiff[];
+}
+
+export interface RolloutStatusEvent {
+  event_type: 'progress' | 'completed' | 'failed' | 'timeout' | 'error';
+  message: string;
+  replicas: number;
+  updated_replicas: number;
+  ready_replicas: number;
+  available_replicas: number;
+}
+
+export interface ContainerImage {
+  name: string;
+  image: string;
+  init: boolean;
+}
+
+export interface SetImageResult {
+  containers: ContainerImage[];
+  rolloutWatchId: string | null;
+}
+
+export interface CronJobRun {
+  name: string;
+  status: 'Running' | 'Succeeded' | 'Failed';
+  manual: boolean;
+  startTime: string | null;
+  completionTime: string | null;
+  durationSeconds: number | null;
+  succeeded: number;
+  failed: number;
+}
+
+export interface ApplyResult {
+  apiVersion: string;
+  kind: string;
+  name: string;
+  namespace: string | null;
+  status: 'applied' | 'conflict' | 'error';
+  message: string | null;
+  resource: any | null;
+}
+
+export interface DiffLine {
+  tag: 'equal' | 'insert' | 'delete';
+  text: string;
+}
+
+export interface ResourceDiff {
+  apiVersion: string;
+  kind: string;
+  name: string;
+  namespace: string | null;
+  exists: boolean;
+  changed: boolean;
+  patch: any[];
+  lines: DiffLine[];
+  error: string | null;
+}
+
 /**
  * Object wrapping all supported Rust commands
  */
@@ -80,9 +302,83 @@ export const commands = {
   listResources: async (
     context: string | undefined,
     kind: string,
-    namespace: string | undefined
+    namespace: string | undefined,
+    query: ListQuery = {}
   ): Promise<any[]> => {
-    return invoke('list_resources', { context, kind, namespace });
+    const page = await commands.listResourcesPage(context, kind, namespace, query);
+    return page.items;
+  },
+
+  /**
+   * Get one page of Kubernetes resources. Pass the returned `continue` token as
+   * `continueToken` to fetch the next page
+   */
+  listResourcesPage: async (
+    context: string | undefined,
+    kind: string,
+    namespace: string | undefined,
+    query: PageQuery = {}
+  ): Promise<ResourcePage> => {
+    return invoke('list_resources', { context, kind, namespace, ...query });
+  },
+
+  /**
+   * List only the metadata of resources. Items keep the `{ metadata }` shape; use this when only
+   * names, labels or counts are needed
+   */
+  listResourceMetadata: async (
+    context: string | undefined,
+    kind: string,
+    namespace?: string,
+    query: PageQuery = {}
+  ): Promise<ResourcePage<{ metadata: any }>> => {
+    return invoke('list_resources', { context, kind, namespace, ...query, metadataOnly: true });
+  },
+
+  /**
+   * Get one page of resources as the server-side Table kubectl prints, including CRD
+   * additionalPrinterColumns. Each row's cells follow `columnDefinitions`
+   */
+  listResourcesTable: async (
+    context: string | undefined,
+    kind: string,
+    namespace: string | undefined,
+    query: PageQuery = {}
+  ): Promise<ResourcePage<TableRow>> => {
+    return invoke('list_resources', { context, kind, namespace, ...query, asTable: true });
+  },
+
+  /**
+   * List resources of the same kind from several contexts in parallel. Unreachable contexts are
+   * reported in `errors` instead of failing the whole call
+   */
+  listResourcesMulti: async (
+    contexts: string[],
+    kind: string,
+    namespace?: string,
+    query: ListQuery = {},
+    timeoutSeconds?: number
+  ): Promise<MultiContextResources> => {
+    return invoke('list_resources_multi', { contexts, kind, namespace, ...query, timeoutSeconds });
+  },
+
+  /**
+   * Compare one resource across contexts, ignoring cluster-assigned metadata and status
+   */
+  diffResourceAcrossContexts: async (
+    contexts: string[],
+    kind: string,
+    name: string,
+    namespace?: string,
+    timeoutSeconds?: number
+  ): Promise<CrossContextDiff> => {
+    return invoke('diff_resource_across_contexts', {
+      contexts,
+      kind,
+      name,
+      namespace,
+      timeoutSeconds,
+    });
   },
 
   /**
@@ -103,9 +399,10 @@ export const commands = {
   startWatchResources: async (
     context: string | undefined,
     kind: string,
-    namespace: string | undefined
+    namespace: string | undefined,
+    query: ListQuery = {}
   ): Promise<string> => {
-    return invoke('start_watch_resources', { context, kind, namespace });
+    return invoke('start_watch_resources', { context, kind, namespace, ...query });
   },
 
   /**
@@ -122,6 +419,13 @@ export const commands = {
     return invoke('list_crd_groups', { context });
   },
 
+  /**
+   * List every API resource the cluster serves, found through API discovery
+   */
+  listApiResources: async (context: string | undefined): Promise<ApiResourceInfo[]> => {
+    return invoke('list_api_resources', { context });
+  },
+
   /**
    * Delete a Kubernetes resource
    */
@@ -129,19 +433,366 @@ export const commands = {
     context: string | undefined,
     kind: string,
     name: string,
-    namespace: string | undefined
+    namespace: string | undefined,
+    options?: DeleteOptions
   ): Promise<void> => {
-    return invoke('delete_resource', { context, kind, name, namespace });
+    return invoke('delete_resource', { context, kind, name, namespace, options });
   },
 
   /**
-   * Execute rollout restart for a Deployment
+   * Delete several resources. Every target is attempted and reported individually
    */
-  rolloutRestartDeployment: async (
+  bulkDeleteResources: async (
+    context: string | undefined,
+    targets: ResourceTarget[],
+    options?: DeleteOptions
+  ): Promise<BulkResult[]> => {
+    return invoke('bulk_delete_resources', { context, targets, options });
+  },
+
+  /**
+   * Rollout restart several Deployments, StatefulSets or DaemonSets
+   */
+  bulkRolloutRestart: async (
+    context: string | undefined,
+    targets: ResourceTarget[]
+  ): Promise<BulkResult[]> => {
+    return invoke('bulk_rollout_restart', { context, targets });
+  },
+
+  /**
+   * Scale several resources to the same replica count
+   */
+  bulkScaleResources: async (
+    context: string | undefined,
+    targets: ResourceTarget[],
+    replicas: number
+  ): Promise<BulkResult[]> => {
+    return invoke('bulk_scale_resources', { context, targets, replicas });
+  },
+
+  /**
+   * Evict a pod through the Eviction API, which respects PodDisruptionBudgets
+   */
+  evictPod: async (
     context: string | undefined,
     name: string,
+    namespace: string,
+    options?: DeleteOptions
+  ): Promise<void> => {
+    return invoke('evict_pod', { context, name, namespace, options });
+  },
+
+  /**
+   * Execute rollout restart for a Deployment, StatefulSet or DaemonSet
+   */
+  rolloutRestart: async (
+    context: string | undefined,
+    kind: string,
+    name: string,
     namespace: string
   ): Promise<void> => {
-    return invoke('rollout_restart_deployment', { context, name, namespace });
+    return invoke('rollout_restart', { context, kind, name, namespace });
+  },
+
+  /**
+   * Pause a Deployment so template changes do not trigger a rollout
+   */
+  rolloutPause: async (context: string | undefined, name: string, namespace: string) => {
+    return invoke<void>('rollout_pause', { context, name, namespace });
+  },
+
+  /**
+   * Resume a paused Deployment
+   */
+  rolloutResume: async (context: string | undefined, name: string, namespace: string) => {
+    return invoke<void>('rollout_resume', { context, name, namespace });
+  },
+
+  /**
+   * Set the rolling update partition of a StatefulSet. Only pods with an ordinal at or above the
+   * partition are updated
+   */
+  setRolloutPartition: async (
+    context: string | undefined,
+    name: string,
+    namespace: string,
+    partition: number
+  ): Promise<void> => {
+    return invoke('set_rollout_partition', { context, name, namespace, partition });
+  },
+
+  /**
+   * Run a CronJob now. Returns the name of the created Job
+   */
+  triggerCronJob: async (
+    context: string | undefined,
+    name: string,
+    namespace: string
+  ): Promise<string> => {
+    return invoke('trigger_cronjob', { context, name, namespace });
+  },
+
+  /**
+   * Suspend a CronJob so no new Jobs are scheduled
+   */
+  suspendCronJob: async (context: string | undefined, name: string, namespace: string) => {
+    return invoke<void>('suspend_cronjob', { context, name, namespace });
+  },
+
+  /**
+   * Resume a suspended CronJob
+   */
+  resumeCronJob: async (context: string | undefined, name: string, namespace: string) => {
+    return invoke<void>('resume_cronjob', { context, name, namespace });
+  },
+
+  /**
+   * Summarize the most recent Jobs of a CronJob, newest first
+   */
+  getCronJobRuns: async (
+    context: string | undefined,
+    name: string,
+    namespace: string,
+    limit?: number
+  ): Promise<CronJobRun[]> => {
+    return invoke('get_cronjob_runs', { context, name, namespace, limit });
+  },
+
+  /**
+   * List the containers and init containers of a workload's pod template with their images
+   */
+  listWorkloadContainers: async (
+    context: string | undefined,
+    kind: string,
+    name: string,
+    namespace: string
+  ): Promise<ContainerImage[]> => {
+    return invoke('list_workload_containers', { context, kind, name, namespace });
+  },
+
+  /**
+   * Set container images on a Deployment, StatefulSet, DaemonSet, CronJob or Job. `images` maps
+   * container names to new images. With `watchRollout` the rollout is followed through
+   * `rollout-status-{rolloutWatchId}` events; Jobs and CronJobs have no rollout and reject it
+   */
+  setImage: async (
+    context: string | undefined,
+    kind: string,
+    name: string,
+    namespace: string,
+    images: Record<string, string>,
+    watchRollout = false,
+    timeoutSeconds?: number
+  ): Promise<SetImageResult> => {
+    return invoke('set_image', {
+      context,
+      kind,
+      name,
+      namespace,
+      images,
+      watchRollout,
+      timeoutSeconds,
+    });
+  },
+
+  /**
+   * List the revisions of a Deployment, StatefulSet or DaemonSet, oldest first, with each pod
+   * template's diff against the current one
+   */
+  getRolloutHistory: async (
+    context: string | undefined,
+    kind: string,
+    name: string,
+    namespace: string
+  ): Promise<RolloutRevision[]> => {
+    return invoke('get_rollout_history', { context, kind, name, namespace });
+  },
+
+  /**
+   * Roll a workload back to a revision, or to the previous one when `toRevision` is omitted
+   */
+  rolloutUndo: async (
+    context: string | undefined,
+    kind: string,
+    name: string,
+    namespace: string,
+    toRevision?: number
+  ): Promise<string> => {
+    return invoke('rollout_undo', { context, kind, name, namespace, toRevision });
+  },
+
+  /**
+   * Follow the rollout of a Deployment, StatefulSet or DaemonSet. Progress is emitted as
+   * `rollout-status-{watchId}` events
+   */
+  watchRolloutStatus: async (
+    context: string | undefined,
+    kind: string,
+    name: string,
+    namespace: string,
+    timeoutSeconds?: number
+  ): Promise<string> => {
+    return invoke('watch_rollout_status', { context, kind, name, namespace, timeoutSeconds });
+  },
+
+  /**
+   * Stop following a rollout
+   */
+  stopRolloutStatus: async (watchId: string): Promise<void> => {
+    return invoke('stop_rollout_status', { watchId });
+  },
+
+  /**
+   * Scale a Deployment, StatefulSet, ReplicaSet or custom resource through its scale subresource
+   */
+  scaleResource: async (
+    context: string | undefined,
+    kind: string,
+    name: string,
+    namespace: string | undefined,
+    replicas: number
+  ): Promise<void> => {
+    return invoke('scale_resource', { context, kind, name, namespace, replicas });
+  },
+
+  /**
+   * Mark a node unschedulable
+   */
+  cordonNode: async (context: string | undefined, name: string): Promise<void> => {
+    return invoke('cordon_node', { context, name });
+  },
+
+  /**
+   * Mark a node schedulable again
+   */
+  uncordonNode: async (context: string | undefined, name: string): Promise<void> => {
+    return invoke('uncordon_node', { context, name });
+  },
+
+  /**
+   * Cordon a node and evict its pods. Progress is emitted as `node-drain-{drainId}` events
+   */
+  drainNode: async (
+    context: string | undefined,
+    name: string,
+    options: DrainOptions = {}
+  ): Promise<string> => {
+    return invoke('drain_node', { context, name, options });
+  },
+
+  /**
+   * Stop a running node drain
+   */
+  stopNodeDrain: async (drainId: string): Promise<void> => {
+    return invoke('stop_node_drain', { drainId });
+  },
+
+  /**
+   * Start streaming container logs. Lines are emitted as `pod-logs-{streamId}` events
+   */
+  streamPodLogs: async (
+    context: string | undefined,
+    name: string,
+    namespace: string,
+    options: PodLogOptions
+  ): Promise<string> => {
+    return invoke('stream_pod_logs', { context, name, namespace, options });
+  },
+
+  /**
+   * Stop a container log stream
+   */
+  stopPodLogs: async (streamId: string): Promise<void> => {
+    return invoke('stop_pod_logs', { streamId });
+  },
+
+  /**
+   * Follow logs from every pod of a workload (Deployment, StatefulSet, DaemonSet, ReplicaSet, Job)
+   * or of a raw label selector when kind is "Selector". Lines are emitted as
+   * `workload-logs-{streamId}` events and the stream is stopped with stopPodLogs
+   */
+  streamWorkloadLogs: async (
+    context: string | undefined,
+    kind: string,
+    name: string,
+    namespace: string,
+    options: PodLogOptions
+  ): Promise<string> => {
+    return invoke('stream_workload_logs', { context, kind, name, namespace, options });
+  },
+
+  /**
+   * Start forwarding a local port to a Pod or Service. Status changes are emitted as
+   * `port-forward-status` events. A random local port is used when localPort is omitted
+   */
+  startPortForward: async (
+    context: string | undefined,
+    kind: 'Pod' | 'Service',
+    name: string,
+    namespace: string,
+    remotePort: number,
+    localPort?: number
+  ): Promise<PortForwardInfo> => {
+    return invoke('start_port_forward', { context, kind, name, namespace, remotePort, localPort });
+  },
+
+  /**
+   * List active port forwards, optionally only those of one context
+   */
+  listPortForwards: async (context?: string): Promise<PortForwardInfo[]> => {
+    return invoke('list_port_forwards', { context });
+  },
+
+  /**
+   * Stop a port forward
+   */
+  stopPortForward: async (id: string): Promise<void> => {
+    return invoke('stop_port_forward', { id });
+  },
+
+  /**
+   * Open an interactive exec session into a pod container. Output is emitted as
+   * `terminal-output` events and `terminal-exit` fires when the remote process ends.
+   * Returns the terminal session id
+   */
+  createExecSession: async (
+    context: string | undefined,
+    podName: string,
+    namespace: string,
+    container?: string,
+    command?: string[]
+  ): Promise<string> => {
+    return invoke('create_exec_session', {
+      contextName: context,
+      podName,
+      namespace,
+      container,
+      command,
+    });
+  },
+
+  /**
+   * Server-side apply one or more YAML documents with the "swimmer" field manager
+   */
+  applyResourceYaml: async (
+    context: string | undefined,
+    yaml: string,
+    namespace: string | undefined,
+    options: { force?: boolean; dryRun?: boolean } = {}
+  ): Promise<ApplyResult[]> => {
+    return invoke('apply_resource_yaml', { context, yaml, namespace, ...options });
+  },
+
+  /**
+   * Preview what applying YAML would change, using a server-side dry-run apply
+   */
+  diffResource: async (
+    context: string | undefined,
+    yaml: string,
+    namespace: string | undefined,
+    force?: boolean
+  ): Promise<ResourceDiff[]> => {
+    return invoke('diff_resource', { context, yaml, namespace, force });
   },
 };
@@ -348,7 +348,12 @@ const MatchedPodsSection: React.FC<{
     const fetchPods = async (silent: boolean) => {
       if (!silent) setIsLoading(true);
       try {
-        const allPods = await commands.listResources(contextId, 'Pods', namespace);
+        const labelSelector = Object.entries(matchLabelsRef.current!)
+          .map(([key, value]) => `${key}=${value}`)
+          .join(',');
+        const allPods = await commands.listResources(contextId, 'Pods', namespace, {
+          labelSelector,
+        });
         if (!cancelled) {
           let filtered = filterPodsForDeployment(
             allPods as KubeResource[],
@@ -451,11 +456,9 @@ const NodePodsSection: React.FC<{
     const fetchPods = async (silent: boolean) => {
       if (!silent) setIsLoading(true);
       try {
-        const allPods = (await commands.listResources(
-          contextId,
-          'Pods',
-          undefined
-        )) as KubeResource[];
+        const allPods = (await commands.listResources(contextId, 'Pods', undefined, {
+          fieldSelector: `spec.nodeName=${nodeName}`,
+        })) as KubeResource[];
         if (!cancelled) {
           setPods(allPods.filter(pod => pod.spec?.nodeName === nodeName));
         }
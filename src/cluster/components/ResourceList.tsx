@@ -2,7 +2,7 @@ import React, { useState, useEffect, useMemo, useRef, useCallback, Fragment } fr
 import './ClusterInfoPane.css';
 import { formatAge } from '../../lib/utils';
 import ClusterOverview from './ClusterOverview';
-import { commands } from '../../api/commands';
+import { commands, BulkResult, ResourceTarget } from '../../api/commands';
 import { listen } from '@tauri-apps/api/event';
 import { usePreferences } from '../../contexts/PreferencesContext';
 
@@ -139,12 +139,18 @@ export interface KubeResource {
   type?: string;
 }
 
+// Kinds that support rollout restart
+const ROLLOUT_KINDS = ['Deployments', 'StatefulSets', 'DaemonSets'];
+const SCALE_KINDS = ['Deployments', 'StatefulSets', 'ReplicaSets'];
+
+type BulkAction = 'delete' | 'rolloutRestart' | 'scale';
+
 // Fetch namespaces from backend
 const fetchNamespaces = async (contextId?: string): Promise<string[]> => {
   try {
-    const namespaceResources = await commands.listResources(contextId, 'Namespaces', undefined);
-    return namespaceResources
-      .map((ns: KubeResource) => ns.metadata.name)
+    const namespaceResources = await commands.listResourceMetadata(contextId, 'Namespaces');
+    return namespaceResources.items
+      .map(ns => ns.metadata.name)
       .filter((name: string | undefined): name is string => name !== undefined);
   } catch (err) {
     console.error('Failed to fetch namespaces:', err);
@@ -201,7 +207,7 @@ const ResourceList: React.FC<ResourceListProps> = ({
   const [showActionDropdown, setShowActionDropdown] = useState(false);
   const [actionModal, setActionModal] = useState<
     | {
-        action: 'delete' | 'rolloutRestart';
+        action: BulkAction;
         resources: KubeResource[];
         capturedContext: string | undefined;
       }
@@ -215,6 +221,7 @@ const ResourceList: React.FC<ResourceListProps> = ({
       }
     | undefined
   >(undefined);
+  const [scaleReplicas, setScaleReplicas] = useState<number>(1);
   const actionDropdownRef = useRef<HTMLDivElement>(null);
   const selectedRowRef = useRef<HTMLTableRowElement | null>(null);
   const pendingScrollUidRef = useRef<string | undefined>(undefined);
@@ -601,7 +608,7 @@ const ResourceList: React.FC<ResourceListProps> = ({
   };
 
   const openActionModal = useCallback(
-    (action: 'delete' | 'rolloutRestart') => {
+    (action: BulkAction) => {
       const selectedResources = filteredResources.filter(r => checkedUids.has(r.metadata.uid));
       if (selectedResources.length === 0) return;
       setActionModal({
@@ -618,39 +625,38 @@ const ResourceList: React.FC<ResourceListProps> = ({
   const executeAction = useCallback(async () => {
     if (!actionModal) return;
     const { action, resources, capturedContext } = actionModal;
-    const progress = { completed: 0, total: resources.length, errors: [] as string[] };
-    setActionProgress({ ...progress });
+    setActionProgress({ completed: 0, total: resources.length, errors: [] });
 
-    for (const resource of resources) {
-      try {
-        if (action === 'delete') {
-          const singularKind = selectedKind ? getSingularKind(selectedKind) : '';
-          await commands.deleteResource(
-            capturedContext,
-            singularKind,
-            resource.metadata.name,
-            resource.metadata.namespace
-          );
-        } else if (action === 'rolloutRestart') {
-          if (!resource.metadata.namespace) continue;
-          await commands.rolloutRestartDeployment(
-            capturedContext,
-            resource.metadata.name,
-            resource.metadata.namespace
-          );
-        }
-        progress.completed++;
-      } catch (err) {
-        progress.completed++;
-        progress.errors.push(
-          `${resource.metadata.name}: ${err instanceof Error ? err.message : String(err)}`
-        );
+    const singularKind = selectedKind ? getSingularKind(selectedKind) : '';
+    const targets: ResourceTarget[] = resources.map(resource => ({
+      kind: singularKind,
+      name: resource.metadata.name,
+      namespace: resource.metadata.namespace,
+    }));
+    try {
+      let results: BulkResult[];
+      if (action === 'delete') {
+        results = await commands.bulkDeleteResources(capturedContext, targets);
+      } else if (action === 'rolloutRestart') {
+        results = await commands.bulkRolloutRestart(capturedContext, targets);
+      } else {
+        results = await commands.bulkScaleResources(capturedContext, targets, scaleReplicas);
       }
-      setActionProgress({ ...progress });
+      setActionProgress({
+        completed: results.length,
+        total: results.length,
+        errors: results.filter(r => !r.success).map(r => `${r.name}: ${r.error}`),
+      });
+    } catch (err) {
+      setActionProgress({
+        completed: resources.length,
+        total: resources.length,
+        errors: [err instanceof Error ? err.message : String(err)],
+      });
     }
 
     setCheckedUids(new Set());
-  }, [actionModal, selectedKind]);
+  }, [actionModal, selectedKind, scaleReplicas]);
 
   useEffect(() => {
     if (!showActionDropdown) return;
@@ -1144,7 +1150,7 @@ const ResourceList: React.FC<ResourceListProps> = ({
                     >
                       Delete
                     </div>
-                    {selectedKind === 'Deployments' && (
+                    {ROLLOUT_KINDS.includes(selectedKind ?? '') && (
                       <div
                         className="action-dropdown-item"
                         onClick={() => openActionModal('rolloutRestart')}
@@ -1152,6 +1158,14 @@ const ResourceList: React.FC<ResourceListProps> = ({
                         Rollout Restart
                       </div>
                     )}
+                    {SCALE_KINDS.includes(selectedKind ?? '') && (
+                      <div
+                        className="action-dropdown-item"
+                        onClick={() => openActionModal('scale')}
+                      >
+                        Scale
+                      </div>
+                    )}
                   </div>
                 )}
               </div>
@@ -1402,7 +1416,9 @@ const ResourceList: React.FC<ResourceListProps> = ({
               <h3>
                 {actionModal.action === 'delete'
                   ? 'Delete Resources'
-                  : 'Rollout Restart Deployments'}
+                  : actionModal.action === 'scale'
+                    ? `Scale ${selectedKind}`
+                    : `Rollout Restart ${selectedKind}`}
               </h3>
               {!actionProgress && (
                 <button className="action-modal-close" onClick={() => setActionModal(undefined)}>
@@ -1413,6 +1429,21 @@ const ResourceList: React.FC<ResourceListProps> = ({
             <div className="action-modal-context">
               Context: <strong>{actionModal.capturedContext || 'default'}</strong>
             </div>
+            {actionModal.action === 'scale' && (
+              <div className="action-modal-context">
+                <label>
+                  Replicas:{' '}
+                  <input
+                    className="action-modal-replicas"
+                    type="number"
+                    min={0}
+                    value={scaleReplicas}
+                    disabled={!!actionProgress}
+                    onChange={e => setScaleReplicas(Math.max(0, Number(e.target.value)))}
+                  />
+                </label>
+              </div>
+            )}
             <div className="action-modal-resources">
               <table className="action-modal-table">
                 <thead>
@@ -1480,7 +1511,11 @@ const ResourceList: React.FC<ResourceListProps> = ({
                     className={`action-modal-btn ${actionModal.action === 'delete' ? 'action-modal-btn-danger' : 'action-modal-btn-primary'}`}
                     onClick={executeAction}
                   >
-                    {actionModal.action === 'delete' ? 'Delete' : 'Restart'}
+                    {actionModal.action === 'delete'
+                      ? 'Delete'
+                      : actionModal.action === 'scale'
+                        ? 'Scale'
+                        : 'Restart'}
                   </button>
                 </Fragment>
               ) : actionProgress.completed === actionProgress.total ? (
@@ -182,6 +182,11 @@ export const createTerminalSession = async (
     invoke('write_to_terminal', { sessionId, data }).catch(console.error);
   });
 
+  // Keep the backend PTY size in sync with the fitted terminal
+  term.onResize(({ rows, cols }) => {
+    invoke('resize_terminal', { sessionId, rows, cols }).catch(console.error);
+  });
+
   // Handle paste (Ctrl+V / Cmd+V)
   term.attachCustomKeyEventHandler(e => {
     if ((e.ctrlKey || e.metaKey) && e.key === 'v' && e.type === 'keydown') {